@@ -0,0 +1,10 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The game engine for Restaurant The Game.
+// The simulator never reads from or writes to the terminal,
+// so it can be driven by the text UI, by tests or by any other front end.
+
+pub mod simulator;
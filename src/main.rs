@@ -3,33 +3,149 @@
 // Please see the file COPYING in the source
 // distribution of this software for license terms.
 
-#[macro_use]
-extern crate rand;
 #[macro_use]
 extern crate text_io;
 mod ui;
+use restaurant_the_game::simulator::restaurant::menu_file;
+use restaurant_the_game::simulator::{GameRules, Money};
+use std::env;
+use std::str::FromStr;
 use ui::UI;
-//mod simulator;
-//use simulator::Simulator;
 
 fn main() {
     println!();
 
-    let mut choice : String = " ".to_owned();
-    
-    println!("Welcome to Restaurant The Game");
-    println!("This is a text based restaurant simulation game");
-    println!("You will be in charge of managing a restaurant.");
-    println!("This includes hiring and firing employees, ordering more inventory,");
-    println!("and changing the price and quality of the food on the menu.");
-    println!("Enter q to quit or enter any key to launch game");
-    
-    choice = read!();
-    if choice == "q".to_owned() {
-        return ;
+    //A saved game can be given with --load <file>
+    //A new game can be replayed from its seed with --seed <number>
+    //and named ahead of time with --name <name>
+    //A script of commands can be run instead of playing with --script <file>
+    //Every command can be written to a file while playing with --record <file>
+    //The game rules can be changed with --debt-days <days>, --target <balance>,
+    //--missed-payments <count> and --days <days>, where 0 turns the rule off,
+    //and --allow-unpaid-payroll keeps the restaurant open when it can't make payroll
+    //A new game can use a menu read from a file with --menu <file>
+    let args: Vec<String> = env::args().collect();
+    let load_path = arg_value(&args, "--load");
+    //A saved game keeps the name, seed, menu and rules it was started with
+    if load_path.is_some() && NEW_GAME_FLAGS.iter().any(|flag| args.iter().any(|arg| arg == flag)) {
+        println!("--load can't be used with {}, a saved game keeps the settings it was started with", NEW_GAME_FLAGS.join(", "));
+        return;
+    }
+    let script_path = arg_value(&args, "--script");
+    let record_path = arg_value(&args, "--record");
+    let mut name = arg_value(&args, "--name").cloned();
+    let seed = match arg_value(&args, "--seed").map(|seed| seed.parse::<u64>()) {
+        Some(Ok(seed)) => Some(seed),
+        Some(Err(_)) => {
+            println!("The seed must be a whole number");
+            return;
+        }
+        None => None,
+    };
+    let menu = match arg_value(&args, "--menu").map(|path| (path, menu_file::load_menu(path))) {
+        Some((_, Ok(menu))) => Some(menu),
+        Some((path, Err(e))) => {
+            println!("Could not read menu {}: {}", path, e);
+            return;
+        }
+        None => None,
+    };
+    let mut rules = GameRules::default();
+    if args.iter().any(|arg| arg == "--allow-unpaid-payroll") {
+        rules.lose_on_unpaid_payroll = false;
+    }
+    match (
+        arg_number::<i64>(&args, "--debt-days"),
+        arg_number::<Money>(&args, "--target"),
+        arg_number::<i64>(&args, "--missed-payments"),
+        arg_number::<i64>(&args, "--days"),
+    ) {
+        (Ok(debt_days), Ok(target), Ok(missed), Ok(days)) => {
+            if let Some(debt_days) = debt_days {
+                rules.max_days_in_debt = Some(debt_days).filter(|days| *days > 0);
+            }
+            if let Some(target) = target {
+                rules.target_balance = Some(target).filter(|target| *target > Money::ZERO);
+            }
+            if let Some(missed) = missed {
+                rules.max_missed_payments = Some(missed).filter(|missed| *missed > 0);
+            }
+            if let Some(days) = days {
+                rules.survive_days = Some(days).filter(|days| *days > 0);
+            }
+        }
+        _ => {
+            println!("--debt-days, --target, --missed-payments and --days must be given numbers");
+            return;
+        }
+    }
+
+    //Scripts run without asking the player anything
+    if script_path.is_some() {
+        name = name.or_else(|| Some("Restaurant".to_owned()));
+    } else {
+        println!("Welcome to Restaurant The Game");
+        println!("This is a text based restaurant simulation game");
+        println!("You will be in charge of managing a restaurant.");
+        println!("This includes hiring and firing employees, ordering more inventory,");
+        println!("and changing the price and quality of the food on the menu.");
+        println!("Enter q to quit or enter any key to launch game");
+
+        let choice : String = read!();
+        if choice == "q" {
+            return ;
+        }
     }
 
-    let mut ui = UI::new();
-    ui.home_page();
+    let mut ui = match load_path {
+        Some(path) => match UI::load(path) {
+            Ok(ui) => ui,
+            Err(e) => {
+                println!("Could not load {}: {}", path, e);
+                return;
+            }
+        },
+        None => UI::new(name, seed, rules, menu),
+    };
+
+    if let Some(path) = record_path {
+        if let Err(e) = ui.record_to(path) {
+            println!("Could not record to {}: {}", path, e);
+            return;
+        }
+    }
+
+    match script_path {
+        Some(path) => {
+            if let Err(e) = ui.run_script(path) {
+                println!("Script stopped at {}", e);
+            }
+        }
+        None => ui.run(),
+    }
+
+}
+
+//Flags that only apply to a new game
+const NEW_GAME_FLAGS: [&str; 8] = [
+    "--name",
+    "--seed",
+    "--menu",
+    "--debt-days",
+    "--target",
+    "--missed-payments",
+    "--days",
+    "--allow-unpaid-payroll",
+];
+
+//Finds the value given after a command line flag
+fn arg_value<'a>(args : &'a [String], flag : &str) -> Option<&'a String> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|pos| args.get(pos + 1))
+}
 
+//Reads the number given after a command line flag
+fn arg_number<T : FromStr>(args : &[String], flag : &str) -> Result<Option<T>, T::Err> {
+    arg_value(args, flag).map(|value| value.parse::<T>()).transpose()
 }
@@ -0,0 +1,999 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following is used as a way to simulate a day.
+// As well as manage the restaurant.
+// The simulator keeps track of how many days have passed and the restaurant.
+// A new simulator is built from a SimConfig, so it never has to ask the user for anything.
+// Nothing here prints; each day is summarized in a DayReport for the front end to show.
+// A simulator can be saved to a file and loaded back later.
+// Every random decision comes from its seeded GameRng, so a game can be replayed from its seed.
+
+pub mod restaurant;
+use restaurant::capacity::Capacity;
+use restaurant::schedule::{self, Shift, Slot, SHIFT_HOURS};
+use restaurant::employee::{self, Employee};
+use restaurant::ingredient::Ingredient;
+use restaurant::menu_file;
+pub use restaurant::menu_file::{Menu, MenuError};
+use restaurant::menu_item::{MenuItem, MAX_PRICE};
+use restaurant::supplier::{PendingOrder, Supplier};
+use restaurant::reputation::Reputation;
+use restaurant::ledger::{Account, Category, Ledger, Period, Statement};
+use restaurant::bank::{self, Bank};
+#[cfg(test)]
+use restaurant::overhead;
+use restaurant::segment::Segment;
+use restaurant::{LostReason, Restaurant, LOST_REASONS};
+mod save;
+pub use save::SaveError;
+mod game_rng;
+pub use game_rng::GameRng;
+pub mod day_report;
+pub use day_report::DayReport;
+pub mod rules;
+pub use rules::{GameOver, GameRules};
+mod sim_error;
+pub use sim_error::SimError;
+pub mod money;
+pub use money::Money;
+use serde::{Deserialize, Serialize};
+
+// Everything needed to start a new game
+// A new config gets a random seed, set seed to replay an earlier game
+// and the default menu, set menu to play with a menu from a file
+pub struct SimConfig {
+    pub name: String,
+    pub seed: u64,
+    pub rules: GameRules,
+    pub menu: Menu,
+}
+
+impl SimConfig {
+    pub fn new(name: String) -> SimConfig {
+        SimConfig {
+            name,
+            seed: rand::random(),
+            rules: GameRules::default(),
+            menu: menu_file::default_menu(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Simulator {
+    day : i64,
+    restaurant :  Restaurant,
+    //Seed the game was started from
+    seed : u64,
+    rng : GameRng,
+    rules : GameRules,
+    //Days in a row the balance has been below zero
+    days_in_debt : i64,
+    //How the game ended, once it has
+    outcome : Option<GameOver>,
+}
+
+impl Simulator {
+    pub fn new(config : SimConfig) -> Simulator {
+        let mut rng = GameRng::new(config.seed);
+        let restaurant = Restaurant::new(config.name, config.menu, &mut rng);
+
+        Simulator {
+            day : 0,
+            restaurant,
+            seed : config.seed,
+            rng,
+            rules : config.rules,
+            days_in_debt : 0,
+            outcome : None,
+        }
+    }
+
+    //Loads a simulator from a save file
+    pub fn load(path : &str) -> Result<Simulator, SaveError> {
+        save::load_game(path)
+    }
+
+    //Saves the simulator to a file
+    pub fn save(&self, path : &str) -> Result<(), SaveError> {
+        save::save_game(self, path)
+    }
+   
+    //Simulates a day in the restaurant
+    //Call the restaurant function to serve the customers
+    //Sales, wages, stock used and stock spoiled are all written down in the ledger
+    //Rent, utilities, insurance and the loan and credit line payments due are paid after payroll,
+    //then the taxes due
+    //Orders due today arrive before the restaurant opens
+    //Stock that has spoiled is thrown away at the end of the day
+    //A new list of potential employees comes in at the start of every week
+    //Unhappy employees may quit at the start of the day, and every employee's morale changes at the end
+    //Employees learn from the day once it is done, see Employee::learn
+    //The game rules are checked once the day is done
+    pub fn sim_day(&mut self) -> DayReport {
+        self.day += 1;
+        if self.day % 7 == 0 {
+            self.update_pot();
+        }
+        let weekday = schedule::get_weekday(self.day);
+        if weekday == 0 {
+            self.restaurant.reset_week();
+        }
+        let quit = self.restaurant.quit_empls(&mut self.rng);
+        let deliveries = self.restaurant.receive_orders(self.day, &mut self.rng);
+        let start_inv : Vec<(String, i64)> = self
+            .restaurant
+            .get_ingredients()
+            .iter()
+            .map(|ingredient| (ingredient.get_name(), ingredient.get_inv()))
+            .collect();
+
+        let capacity = self.restaurant.calc_day_capacity(weekday);
+        let (mut slots, mut segments) = self.restaurant.generate_customers(weekday, &mut self.rng);
+        let customers_served = slots.iter().map(|slot| slot.served).sum();
+        let service = self.restaurant.serve_customers(self.day, &mut self.rng);
+        for (slot, (_, sales)) in slots.iter_mut().zip(&service.slot_sales) {
+            slot.sales = *sales;
+        }
+        for (segment, (_, sales)) in segments.iter_mut().zip(&service.segment_sales) {
+            segment.sales = *sales;
+        }
+        let sales = service.sales;
+        let ingredients_used = start_inv
+            .into_iter()
+            .map(|(name, inv)| {
+                let used = inv - self.restaurant.get_inv(name.clone());
+                (name, used)
+            })
+            .collect();
+        //The stock was paid for when it was ordered, so waste doesn't change the balance
+        //What it cost still counts against the day's profit
+        let waste = self.restaurant.spoil_ingredients(self.day);
+        let labor_cost = self.restaurant.calc_empl_day_cost(weekday);
+        let overtime_hours = self.restaurant.calc_overtime(weekday);
+        self.restaurant.work_day(weekday);
+        self.restaurant.update_morale(weekday, &slots);
+        let progress = self.restaurant.learn(weekday);
+        let payroll_paid = self.restaurant.get_cash() >= labor_cost;
+        self.restaurant.pay(self.day, Category::Payroll, Account::Payroll, labor_cost, "Wages".to_owned());
+        let overhead = self.restaurant.pay_overhead(self.day, customers_served);
+        let payments = self.restaurant.collect_payments(self.day);
+        let taxes = self.restaurant.pay_taxes(self.day);
+        let statement = self.restaurant.get_ledger().calc_statement(self.day, self.day);
+
+        if self.restaurant.get_cash().is_negative() {
+            self.days_in_debt += 1;
+        } else {
+            self.days_in_debt = 0;
+        }
+        if self.outcome.is_none() {
+            self.outcome = self.rules.check(
+                self.day,
+                self.get_net_balance(),
+                self.days_in_debt,
+                payroll_paid,
+                self.restaurant.get_bank().get_missed(),
+            );
+        }
+
+        DayReport {
+            day: self.day,
+            seed: self.seed,
+            quit,
+            progress,
+            deliveries,
+            weekday,
+            customers_served,
+            bottleneck: Restaurant::find_bottleneck(&slots).unwrap_or(capacity.get_bottleneck().0),
+            capacity: capacity.stages,
+            turned_away: LOST_REASONS
+                .iter()
+                .map(|reason| {
+                    let count = match reason {
+                        LostReason::Capacity => slots.iter().map(|slot| slot.wanted - slot.served).sum(),
+                        LostReason::Stockout => service.left_stockout,
+                        LostReason::Price => service.left_price,
+                    };
+                    (*reason, count)
+                })
+                .collect(),
+            slots,
+            segments,
+            reviews: service.reviews,
+            rating: self.restaurant.get_reputation().get_rating(),
+            stockouts: service.stockouts,
+            priced_out: service.priced_out,
+            items_sold: service.items_sold,
+            quality_served: service.quality_served,
+            ingredients_used,
+            spoiled: waste.spoiled,
+            waste_cost: waste.cost,
+            sales,
+            sales_tax: statement
+                .cash_in
+                .iter()
+                .find(|(category, _)| *category == Category::Tax)
+                .map_or(Money::ZERO, |(_, amount)| *amount),
+            labor_cost,
+            overtime_hours,
+            cost_of_goods: statement.get_amount(Account::CostOfGoods),
+            overhead,
+            taxes,
+            payments,
+            debt: self.restaurant.get_debt(),
+            profit: statement.net_income,
+            cash_flow: statement.get_net_cash(),
+            game_over: self.outcome,
+        }
+    }
+
+    //Getters
+    pub fn get_day(&self) -> i64 {
+        self.day
+    }
+
+    pub fn get_inv(&self, name : String) -> i64{
+        self.restaurant.get_inv(name)
+    }
+
+    pub fn get_name(&self) -> String {
+        self.restaurant.get_name()
+    }
+
+    //Day of the week of the next day to be simulated, 0 is Monday
+    pub fn get_weekday(&self) -> i64 {
+        schedule::get_weekday(self.day + 1)
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn get_rules(&self) -> &GameRules {
+        &self.rules
+    }
+
+    pub fn get_days_in_debt(&self) -> i64 {
+        self.days_in_debt
+    }
+
+    //How the game ended, or None while it is still going
+    pub fn get_outcome(&self) -> Option<GameOver> {
+        self.outcome
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    //Score for the game so far
+    pub fn get_score(&self) -> i64 {
+        rules::calc_score(self.day, self.get_net_balance(), self.outcome)
+    }
+    
+    pub fn hired_len(&self) -> i64 {
+         self.restaurant.get_hired_empls().len() as i64
+    }
+
+    pub fn pot_len(&self) -> i64 {
+        self.restaurant.get_pot_empls().len() as i64
+    }
+
+    pub fn get_cash(&self) -> Money {
+        self.restaurant.get_cash()
+    }
+
+    pub fn get_ledger(&self) -> &Ledger {
+        self.restaurant.get_ledger()
+    }
+
+    //Loans and credit line owed
+    pub fn get_bank(&self) -> &Bank {
+        self.restaurant.get_bank()
+    }
+
+    //Cash on hand less everything owed to the bank
+    pub fn get_net_balance(&self) -> Money {
+        self.restaurant.get_cash() - self.restaurant.get_debt()
+    }
+
+    //Weekly interest rate of a loan taken out today
+    pub fn get_loan_rate(&self) -> f64 {
+        self.restaurant.calc_loan_rate(self.day + 1)
+    }
+
+    //Weekly interest rate the credit line is charged at today
+    pub fn get_credit_rate(&self) -> f64 {
+        bank::calc_credit_rate(self.get_loan_rate())
+    }
+
+    //Most that can be borrowed as a new loan today
+    pub fn get_loan_limit(&self) -> Money {
+        self.restaurant.calc_loan_limit(self.day + 1)
+    }
+
+    //Profit and loss and cash flow for the given period, up to the last day simulated
+    //Before the first day it is the period the first day falls in
+    pub fn get_statement(&self, period: Period) -> Statement {
+        let day = self.day.max(1);
+        let (first_day, last_day) = period.get_days(day);
+        self.restaurant.get_ledger().calc_statement(first_day, last_day.min(day))
+    }
+
+    //Quality of the given menu item made from the stock on hand
+    pub fn get_item_quality(&self, name: String) -> f64 {
+        self.restaurant.get_item_quality(name)
+    }
+
+    pub fn get_price(&self, name: String) -> Money {
+        self.restaurant.get_price(name)
+    }
+
+    //Price customers expect to pay for the given menu item
+    pub fn get_reference_price(&self, name: String) -> Money {
+        self.restaurant.get_reference_price(name)
+    }
+
+    //Chance an average customer buys the given menu item at its price
+    pub fn get_purchase_chance(&self, name: String) -> f64 {
+        self.restaurant.calc_purchase_chance(name, &Segment::average(), 1.0)
+    }
+
+    //Chance an average customer from the given segment buys the given menu item at its price
+    pub fn get_segment_chance(&self, name: String, segment: &Segment) -> f64 {
+        self.restaurant.calc_purchase_chance(name, segment, 1.0)
+    }
+
+    //The kinds of customers that come in
+    pub fn get_segments(&self) -> &[Segment] {
+        self.restaurant.get_segments()
+    }
+
+    //What customers have said about the restaurant
+    pub fn get_reputation(&self) -> &Reputation {
+        self.restaurant.get_reputation()
+    }
+
+    //Number of the given menu item that can be made from the ingredients in stock
+    pub fn get_servings(&self, name: String) -> i64 {
+        self.restaurant.get_servings(name)
+    }
+
+    pub fn get_ingredient_quality(&self, name: String) -> i64 {
+        self.restaurant.get_ingredient_quality(name)
+    }
+
+    pub fn get_ingredients(&self) -> &[Ingredient] {
+        self.restaurant.get_ingredients()
+    }
+
+    pub fn get_suppliers(&self) -> &[Supplier] {
+        self.restaurant.get_suppliers()
+    }
+
+    //Orders that have been paid for but haven't arrived yet
+    pub fn get_pending_orders(&self) -> &[PendingOrder] {
+        self.restaurant.get_pending_orders()
+    }
+
+    pub fn get_menu(&self) -> &[MenuItem] {
+        self.restaurant.get_menu()
+    }
+
+    //Customers every stage of service can handle with the staff working the given time slot
+    pub fn get_capacity(&self, weekday : i64, slot : Slot) -> Capacity {
+        self.restaurant.calc_capacity(weekday, slot)
+    }
+
+    pub fn get_hired_empls(&self) -> &[Employee] {
+        self.restaurant.get_hired_empls()
+    }
+
+    pub fn get_pot_empls(&self) -> &[Employee] {
+        self.restaurant.get_pot_empls()
+    }
+
+    //Setters
+    //Sets the quality an ingredient is bought at
+    pub fn set_ingredient_quality(&mut self, name: String, new_quality : i64) -> Result<(), SimError> {
+        self.check_ingredient(&name)?;
+        if !(1..=3).contains(&new_quality) {
+            return Err(SimError::InvalidQuality(new_quality));
+        }
+        self.restaurant.set_ingredient_quality(name, new_quality);
+        Ok(())
+    }
+
+    pub fn set_item_price(&mut self, name : String, new_price : Money) -> Result<(), SimError> {
+        self.check_item(&name)?;
+        if new_price.is_negative() {
+            return Err(SimError::NegativePrice(new_price));
+        }
+        if new_price > MAX_PRICE {
+            return Err(SimError::OverMaxPrice(new_price));
+        }
+        self.restaurant.set_item_price(name,new_price);
+        Ok(())
+    }
+
+    // New list of potential employees
+    pub fn update_pot(&mut self){
+        self.restaurant.generate_pot_empls(&mut self.rng);
+    }
+
+    //Hire an employee based on position given in list
+    pub fn hire_empl(&mut self, position : usize) -> Result<(), SimError> {
+        let len = self.restaurant.get_pot_empls().len();
+        if position >= len {
+            return Err(SimError::IndexOutOfRange { index : position, len });
+        }
+        let new_emp = self.restaurant.get_pot_empl(position);
+        self.restaurant.hire_emp(new_emp);
+        Ok(())
+    }
+
+    //Adds a shift to the schedule of the hired employee at the given position
+    pub fn schedule_shift(&mut self, position : usize, weekday : i64, shift : Shift) -> Result<(), SimError> {
+        self.check_shift(position, weekday)?;
+        let empl = self.restaurant.get_hired_empl(position);
+        if empl.works(weekday, shift) {
+            return Err(SimError::AlreadyScheduled);
+        }
+        if empl.get_sched_hours() + SHIFT_HOURS > empl.get_max_hours() {
+            return Err(SimError::OverMaxHours(empl.get_max_hours()));
+        }
+        self.restaurant.add_shift(position, weekday, shift);
+        Ok(())
+    }
+
+    //Takes a shift off the schedule of the hired employee at the given position
+    pub fn unschedule_shift(&mut self, position : usize, weekday : i64, shift : Shift) -> Result<(), SimError> {
+        self.check_shift(position, weekday)?;
+        if !self.restaurant.remove_shift(position, weekday, shift) {
+            return Err(SimError::NotScheduled);
+        }
+        Ok(())
+    }
+
+    //Checks that there is a hired employee at the given position and the day of the week exists
+    fn check_shift(&self, position : usize, weekday : i64) -> Result<(), SimError> {
+        let len = self.restaurant.get_hired_empls().len();
+        if position >= len {
+            return Err(SimError::IndexOutOfRange { index : position, len });
+        }
+        if !(0..7).contains(&weekday) {
+            return Err(SimError::InvalidWeekday(weekday));
+        }
+        Ok(())
+    }
+
+    //Fire an employee based on position given in list
+    pub fn fire_empl(&mut self, position : usize) -> Result<(), SimError> {
+        let len = self.restaurant.get_hired_empls().len();
+        if position >= len {
+            return Err(SimError::IndexOutOfRange { index : position, len });
+        }
+        let fired_id = self.restaurant.get_hired_empl(position).get_id();
+        self.restaurant.fire_emp(fired_id);
+        Ok(())
+    }
+
+    //Sends the hired employee at the given position on a training course for the given position
+    //Paid for on the current day, see employee::COURSE_COST
+    pub fn train_empl(&mut self, position : usize, posit : i64) -> Result<(), SimError> {
+        let len = self.restaurant.get_hired_empls().len();
+        if position >= len {
+            return Err(SimError::IndexOutOfRange { index : position, len });
+        }
+        if !employee::POSITIONS.contains(&posit) {
+            return Err(SimError::InvalidPosition(posit));
+        }
+        let empl = self.restaurant.get_hired_empl(position);
+        if empl.is_training() {
+            return Err(SimError::AlreadyTraining);
+        }
+        if empl.get_posit() == posit && empl.get_rating() >= employee::MAX_RATING {
+            return Err(SimError::MaxRating);
+        }
+        let balance = self.restaurant.get_cash();
+        if employee::COURSE_COST > balance {
+            return Err(SimError::InsufficientFunds { cost : employee::COURSE_COST, balance });
+        }
+        self.restaurant.train_empl(position, posit, self.day + 1);
+        Ok(())
+    }
+
+    //Gives the hired employee at the given position the raise they asked for
+    //Returns their new wage
+    pub fn grant_raise(&mut self, position : usize) -> Result<Money, SimError> {
+        let len = self.restaurant.get_hired_empls().len();
+        if position >= len {
+            return Err(SimError::IndexOutOfRange { index : position, len });
+        }
+        self.restaurant.grant_raise(position).ok_or(SimError::NoRaiseAsked)
+    }
+
+    //Borrows the given amount on the current day, paid back weekly over the given weeks
+    //The rate is set by the restaurant's recent profit, see bank
+    //Returns the id of the new loan
+    pub fn take_loan(&mut self, amount : Money, weeks : i64) -> Result<i64, SimError> {
+        self.check_borrow(amount)?;
+        if !(bank::MIN_WEEKS..=bank::MAX_WEEKS).contains(&weeks) {
+            return Err(SimError::InvalidTerm(weeks));
+        }
+        let limit = self.get_loan_limit();
+        if amount > limit {
+            return Err(SimError::OverBorrowLimit { limit, amount });
+        }
+        Ok(self.restaurant.take_loan(amount, weeks, self.day + 1))
+    }
+
+    //Draws the given amount on the credit line on the current day
+    pub fn draw_credit(&mut self, amount : Money) -> Result<(), SimError> {
+        self.check_borrow(amount)?;
+        let limit = self.restaurant.get_bank().calc_credit_left();
+        if amount > limit {
+            return Err(SimError::OverBorrowLimit { limit, amount });
+        }
+        self.restaurant.draw_credit(amount, self.day + 1);
+        Ok(())
+    }
+
+    //Pays back up to the given amount of the credit line on the current day
+    //Returns the amount paid, which is never more than what is owed
+    pub fn repay_credit(&mut self, amount : Money) -> Result<Money, SimError> {
+        if amount <= Money::ZERO {
+            return Err(SimError::InvalidAmount(amount));
+        }
+        let owed = self.restaurant.get_bank().get_credit();
+        if owed.is_zero() {
+            return Err(SimError::NothingOwed);
+        }
+        let cost = amount.min(owed);
+        let balance = self.restaurant.get_cash();
+        if cost > balance {
+            return Err(SimError::InsufficientFunds { cost, balance });
+        }
+        Ok(self.restaurant.repay_credit(amount, self.day + 1))
+    }
+
+    //Checks that the amount can be borrowed at all
+    fn check_borrow(&self, amount : Money) -> Result<(), SimError> {
+        if amount <= Money::ZERO {
+            return Err(SimError::InvalidAmount(amount));
+        }
+        if self.restaurant.get_bank().get_missed() > 0 {
+            return Err(SimError::MissedPayment);
+        }
+        Ok(())
+    }
+
+    //Checks that an item with the given name is on the menu
+    fn check_item(&self, name : &str) -> Result<(), SimError> {
+        if self.restaurant.get_menu().iter().any(|item| item.get_name() == name) {
+            Ok(())
+        } else {
+            Err(SimError::UnknownItem(name.to_owned()))
+        }
+    }
+
+    //Checks that an ingredient with the given name is stocked
+    fn check_ingredient(&self, name : &str) -> Result<(), SimError> {
+        if self.restaurant.get_ingredients().iter().any(|ingredient| ingredient.get_name() == name) {
+            Ok(())
+        } else {
+            Err(SimError::UnknownIngredient(name.to_owned()))
+        }
+    }
+    
+    //Orders the chosen ingredient from the first supplier that sells it
+    pub fn order_inv(&mut self, name : String, inc_amount : i64) -> Result<(), SimError> {
+        self.check_ingredient(&name)?;
+        let supplier = self
+            .restaurant
+            .get_suppliers()
+            .iter()
+            .find(|supplier| supplier.sells(&name))
+            .map(|supplier| supplier.get_name())
+            .ok_or_else(|| SimError::NotSold { supplier : "any supplier".to_owned(), ingredient : name.clone() })?;
+        self.order_from(supplier, name, inc_amount)
+    }
+
+    //Orders the chosen ingredient from the chosen supplier at the ingredient's current quality
+    //Pays the amount ordered times the supplier's price, less any bulk discount, on the current day
+    //The order doesn't go through if the restaurant can't afford it, if the cost is too large to count
+    //or if it is smaller than the supplier's minimum order
+    //The order is added to the pending orders, or stocked right away if the supplier has no lead time
+    pub fn order_from(&mut self, supplier : String, name : String, inc_amount : i64) -> Result<(), SimError> {
+        self.check_ingredient(&name)?;
+        if inc_amount <= 0 {
+            return Err(SimError::InvalidQuantity(inc_amount));
+        }
+        let quality = self.restaurant.get_ingredient_quality(name.clone());
+        let seller = self
+            .restaurant
+            .get_supplier(&supplier)
+            .ok_or_else(|| SimError::UnknownSupplier(supplier.clone()))?;
+        let unit_cost = seller
+            .get_unit_cost(&name, quality, inc_amount)
+            .ok_or_else(|| SimError::NotSold { supplier : supplier.clone(), ingredient : name.clone() })?;
+        if inc_amount < seller.get_min_order() {
+            return Err(SimError::BelowMinimumOrder { min : seller.get_min_order(), amount : inc_amount });
+        }
+        let lead_time = seller.get_lead_time();
+        //An amount so large its cost can't even be counted could never be paid for
+        let cost = seller
+            .calc_order_cost(&name, quality, inc_amount)
+            .ok_or(SimError::InvalidQuantity(inc_amount))?;
+        let balance = self.restaurant.get_cash();
+        if cost > balance {
+            return Err(SimError::InsufficientFunds { cost, balance });
+        }
+        let memo = format!("{} {} from {}", inc_amount, name, supplier);
+        self.restaurant.pay(self.day + 1, Category::Purchase, Account::Inventory, cost, memo);
+
+        let order = PendingOrder {
+            supplier,
+            ingredient : name,
+            quantity : inc_amount,
+            quality,
+            unit_cost,
+            arrives_on : self.day + lead_time,
+        };
+        if lead_time == 0 {
+            self.restaurant.stock_order(&order, inc_amount, self.day);
+        } else {
+            self.restaurant.place_order(order);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use restaurant::supplier::Delivery;
+
+#[test]
+fn sim_day_report_test() {
+    let mut sim = Simulator::new(SimConfig::new("Test".to_owned()));
+    let cash = sim.get_cash();
+    let report = sim.sim_day();
+
+    assert_eq!(1, report.day);
+    assert_eq!(1, sim.get_day());
+    assert_eq!(100 - sim.get_inv("Patty".to_owned()), report.get_sold("Burger"));
+    assert_eq!(report.get_sold("Burger"), report.get_used("Bun"));
+    assert_eq!(report.get_sold("Fries") * 2, report.get_used("Potato"));
+    assert_eq!(report.get_sold("Fries") + report.get_sold("Soda"), report.get_used("Cup"));
+    if report.get_sold("Burger") > 0 {
+        assert_eq!(1.0, report.get_quality_served("Burger"));
+    }
+    assert_eq!(sim.get_cash() - cash, report.cash_flow);
+    //Day 1 starts the month, so insurance is paid along with the utilities
+    let overhead: Money = report.overhead.iter().map(|(_, amount)| amount).sum();
+    assert_eq!(overhead::INSURANCE, report.get_paid(Account::Insurance));
+    assert_eq!(report.sales + report.sales_tax - report.labor_cost - overhead, report.cash_flow);
+    let profit = report.sales - report.cost_of_goods - report.labor_cost - report.waste_cost - overhead;
+    assert_eq!(profit, report.profit);
+    let slot_sales: Money = report.slots.iter().map(|slot| slot.sales).sum();
+    assert_eq!(report.sales, slot_sales);
+    let served: i64 = report.slots.iter().map(|slot| slot.served).sum();
+    assert_eq!(report.customers_served, served);
+}
+
+#[test]
+fn overhead_week_test() {
+    let mut sim = Simulator::new(SimConfig::new("Test".to_owned()));
+    let mut sales_tax = Money::ZERO;
+    for _ in 0..6 {
+        let report = sim.sim_day();
+        assert_eq!(Money::ZERO, report.get_paid(Account::Rent));
+        sales_tax += report.sales_tax;
+    }
+    //Rent and the week's sales tax are paid at the end of Sunday
+    let report = sim.sim_day();
+    sales_tax += report.sales_tax;
+    assert_eq!(overhead::RENT, report.get_paid(Account::Rent));
+    assert_eq!(sales_tax, report.get_paid(Account::SalesTax));
+    assert_eq!(Money::ZERO, sim.get_ledger().get_balance(Account::SalesTax));
+}
+
+#[test]
+fn same_seed_replay_test() {
+    let mut config = SimConfig::new("Test".to_owned());
+    config.seed = 2019;
+    let mut first = Simulator::new(config);
+    let mut config = SimConfig::new("Test".to_owned());
+    config.seed = 2019;
+    let mut second = Simulator::new(config);
+
+    for _ in 0..14 {
+        let first_report = first.sim_day();
+        let second_report = second.sim_day();
+        assert_eq!(2019, first_report.seed);
+        assert_eq!(first_report.customers_served, second_report.customers_served);
+        assert_eq!(first_report.items_sold, second_report.items_sold);
+    }
+    assert_eq!(first.get_cash(), second.get_cash());
+    let first_names: Vec<String> = first.get_pot_empls().iter().map(|e| e.get_name()).collect();
+    let second_names: Vec<String> = second.get_pot_empls().iter().map(|e| e.get_name()).collect();
+    assert_eq!(first_names, second_names);
+}
+
+#[test]
+fn game_over_test() {
+    let mut config = SimConfig::new("Test".to_owned());
+    config.rules = GameRules {
+        max_days_in_debt: None,
+        lose_on_unpaid_payroll: false,
+        max_missed_payments: None,
+        target_balance: None,
+        survive_days: Some(3),
+    };
+    let mut sim = Simulator::new(config);
+
+    assert!(sim.sim_day().game_over.is_none());
+    assert!(sim.sim_day().game_over.is_none());
+    assert_eq!(Some(GameOver::Survived), sim.sim_day().game_over);
+    assert!(sim.is_over());
+}
+
+#[test]
+fn bankrupt_game_test() {
+    let mut config = SimConfig::new("Test".to_owned());
+    config.rules.lose_on_unpaid_payroll = false;
+    let mut sim = Simulator::new(config);
+    sim.restaurant.pay(1, Category::Payroll, Account::Payroll, Money::from_dollars(5000), "Wages".to_owned());
+
+    for _ in 0..3 {
+        sim.sim_day();
+    }
+    assert_eq!(3, sim.get_days_in_debt());
+    assert_eq!(Some(GameOver::Bankrupt), sim.get_outcome());
+}
+
+#[test]
+fn loan_test() {
+    let mut sim = Simulator::new(SimConfig::new("Test".to_owned()));
+    let limit = sim.get_loan_limit();
+    assert_eq!(Err(SimError::InvalidAmount(Money::ZERO)), sim.take_loan(Money::ZERO, 8));
+    assert_eq!(Err(SimError::InvalidTerm(2)), sim.take_loan(Money::from_dollars(500), 2));
+    let amount = limit + Money::from_dollars(1);
+    assert_eq!(Err(SimError::OverBorrowLimit { limit, amount }), sim.take_loan(amount, 8));
+    assert_eq!(Money::from_dollars(1000), sim.get_cash());
+
+    assert_eq!(Ok(1), sim.take_loan(Money::from_dollars(500), 8));
+    assert_eq!(Money::from_dollars(1500), sim.get_cash());
+    //Borrowing doesn't change the balance that counts toward winning
+    assert_eq!(Money::from_dollars(1000), sim.get_net_balance());
+    assert_eq!(Err(SimError::NothingOwed), sim.repay_credit(Money::from_dollars(10)));
+    assert_eq!(Ok(()), sim.draw_credit(Money::from_dollars(100)));
+    assert_eq!(Ok(Money::from_dollars(100)), sim.repay_credit(Money::from_dollars(300)));
+    assert_eq!(Money::from_dollars(500), sim.get_bank().get_debt());
+
+    //The first payment comes out a week later
+    sim.restaurant.receive(1, Category::Capital, Account::Equity, Money::from_dollars(5000), "Owner".to_owned());
+    for _ in 0..7 {
+        assert!(sim.sim_day().payments.is_empty());
+    }
+    let report = sim.sim_day();
+    assert_eq!(1, report.payments.len());
+    assert!(report.debt < Money::from_dollars(500));
+}
+
+#[test]
+fn training_test() {
+    let mut sim = Simulator::new(SimConfig::new("Test".to_owned()));
+    assert_eq!(Err(SimError::InvalidPosition(7)), sim.train_empl(0, 7));
+    assert_eq!(Err(SimError::NoRaiseAsked), sim.grant_raise(0));
+    assert_eq!(Ok(()), sim.train_empl(0, employee::COOK));
+    assert_eq!(Err(SimError::AlreadyTraining), sim.train_empl(0, employee::COOK));
+    assert_eq!(Money::from_dollars(1000) - employee::COURSE_COST, sim.get_cash());
+
+    let mut trained = Vec::new();
+    for _ in 0..employee::COURSE_DAYS {
+        trained.extend(sim.sim_day().progress.into_iter().filter(|progress| progress.trained));
+    }
+    assert_eq!(1, trained.len());
+    assert_eq!(5 + employee::COURSE_GAIN, trained[0].rating);
+    let wage = trained[0].asked_wage.unwrap();
+    assert_eq!(Ok(wage), sim.grant_raise(0));
+    assert_eq!(Err(SimError::NoRaiseAsked), sim.grant_raise(0));
+}
+
+#[test]
+fn default_game_test() {
+    let mut config = SimConfig::new("Test".to_owned());
+    config.rules.lose_on_unpaid_payroll = false;
+    config.rules.max_days_in_debt = None;
+    let mut sim = Simulator::new(config);
+    sim.take_loan(Money::from_dollars(1000), 4).unwrap();
+    sim.restaurant.pay(1, Category::Payroll, Account::Payroll, Money::from_dollars(5000), "Wages".to_owned());
+
+    //Every weekly payment is missed, the third one on day 22
+    for _ in 0..22 {
+        sim.sim_day();
+    }
+    assert!(sim.is_over());
+    assert_eq!(Some(GameOver::Defaulted), sim.get_outcome());
+    assert_eq!(Err(SimError::MissedPayment), sim.draw_credit(Money::from_dollars(10)));
+}
+
+#[test]
+fn order_inv_test() {
+    let mut sim = Simulator::new(SimConfig::new("Test".to_owned()));
+    sim.order_inv("Patty".to_owned(), 10).unwrap();
+    assert_eq!(110, sim.get_inv("Patty".to_owned()));
+    assert_eq!(Money::from_dollars(980), sim.get_cash());
+    //Buns run out before patties
+    assert_eq!(100, sim.get_servings("Burger".to_owned()));
+}
+
+#[test]
+fn order_inv_error_test() {
+    let mut sim = Simulator::new(SimConfig::new("Test".to_owned()));
+    assert_eq!(
+        Err(SimError::InsufficientFunds { cost: Money::from_dollars(2000), balance: Money::from_dollars(1000) }),
+        sim.order_inv("Patty".to_owned(), 1000)
+    );
+    assert_eq!(Err(SimError::InvalidQuantity(-5)), sim.order_inv("Patty".to_owned(), -5));
+    assert_eq!(Err(SimError::InvalidQuantity(i64::MAX)), sim.order_inv("Patty".to_owned(), i64::MAX));
+    assert_eq!(
+        Err(SimError::UnknownIngredient("Burger".to_owned())),
+        sim.order_inv("Burger".to_owned(), 5)
+    );
+    //Nothing changes when an order is turned down
+    assert_eq!(100, sim.get_inv("Patty".to_owned()));
+    assert_eq!(Money::from_dollars(1000), sim.get_cash());
+}
+
+#[test]
+fn empl_index_error_test() {
+    let mut sim = Simulator::new(SimConfig::new("Test".to_owned()));
+    assert_eq!(Err(SimError::IndexOutOfRange { index: 10, len: 10 }), sim.hire_empl(10));
+    assert_eq!(Err(SimError::IndexOutOfRange { index: 3, len: 3 }), sim.fire_empl(3));
+    assert!(sim.hire_empl(0).is_ok());
+    assert_eq!(4, sim.hired_len());
+    assert!(sim.fire_empl(0).is_ok());
+    assert_eq!(3, sim.hired_len());
+}
+
+#[test]
+fn schedule_error_test() {
+    let mut sim = Simulator::new(SimConfig::new("Test".to_owned()));
+    assert_eq!(Err(SimError::AlreadyScheduled), sim.schedule_shift(0, 0, Shift::Lunch));
+    assert_eq!(Err(SimError::OverMaxHours(56)), sim.schedule_shift(0, 0, Shift::Morning));
+    assert_eq!(Err(SimError::InvalidWeekday(7)), sim.unschedule_shift(0, 7, Shift::Lunch));
+    assert_eq!(Ok(()), sim.unschedule_shift(0, 0, Shift::Lunch));
+    assert_eq!(Err(SimError::NotScheduled), sim.unschedule_shift(0, 0, Shift::Lunch));
+    assert_eq!(Ok(()), sim.schedule_shift(0, 0, Shift::Morning));
+}
+
+#[test]
+fn set_item_error_test() {
+    let mut sim = Simulator::new(SimConfig::new("Test".to_owned()));
+    assert_eq!(Err(SimError::NegativePrice(Money::from_dollars(-1))), sim.set_item_price("Fries".to_owned(), Money::from_dollars(-1)));
+    let too_much = MAX_PRICE + Money::from_cents(1);
+    assert_eq!(Err(SimError::OverMaxPrice(too_much)), sim.set_item_price("Fries".to_owned(), too_much));
+    assert!(sim.set_item_price("Fries".to_owned(), MAX_PRICE).is_ok());
+    assert_eq!(Err(SimError::InvalidQuality(4)), sim.set_ingredient_quality("Potato".to_owned(), 4));
+    assert_eq!(
+        Err(SimError::UnknownItem("Taco".to_owned())),
+        sim.set_item_price("Taco".to_owned(), Money::from_dollars(3))
+    );
+    assert!(sim.set_ingredient_quality("Potato".to_owned(), 3).is_ok());
+    assert!(sim.set_ingredient_quality("Cup".to_owned(), 3).is_ok());
+    assert_eq!(3, sim.get_ingredient_quality("Potato".to_owned()));
+    //The fries on hand were made from quality 1 stock
+    assert_eq!(1.0, sim.get_item_quality("Fries".to_owned()));
+}
+
+#[test]
+fn custom_menu_test() {
+    let mut config = SimConfig::new("Test".to_owned());
+    config.menu = menu_file::parse_toml(
+        r#"
+        [[ingredient]]
+        name = "Tortilla"
+        supply_cost = [1.0, 1.25, 1.5]
+        starting_inv = 50
+
+        [[item]]
+        name = "Taco"
+        category = "main"
+        price = 3.0
+        recipe = { Tortilla = 1 }
+        "#,
+    )
+    .unwrap();
+    let mut sim = Simulator::new(config);
+    sim.order_inv("Tortilla".to_owned(), 10).unwrap();
+    assert_eq!(Money::from_dollars(990), sim.get_cash());
+    assert!(sim.order_inv("Patty".to_owned(), 10).is_err());
+    let report = sim.sim_day();
+    //Every customer orders a taco, and either buys it or thinks it costs too much
+    let priced_out: i64 = report.priced_out.iter().map(|(_, missed)| *missed).sum();
+    assert_eq!(report.customers_served, report.get_sold("Taco") + priced_out);
+}
+
+#[test]
+fn spoilage_test() {
+    let mut config = SimConfig::new("Test".to_owned());
+    config.rules.lose_on_unpaid_payroll = false;
+    let mut sim = Simulator::new(config);
+    let mut spoiled = 0;
+    //The starting patties keep for 3 days
+    for _ in 0..3 {
+        let report = sim.sim_day();
+        spoiled += report.get_spoiled("Patty");
+        assert_eq!(Money::from_dollars(2) * report.get_spoiled("Patty") + Money::from_cents(50) * report.get_spoiled("Bun"), report.waste_cost);
+    }
+    assert!(spoiled > 0);
+    assert_eq!(0, sim.get_inv("Patty".to_owned()));
+}
+
+#[test]
+fn order_from_test() {
+    let mut sim = Simulator::new(SimConfig::new("Test".to_owned()));
+    assert_eq!(
+        Err(SimError::BelowMinimumOrder { min : 50, amount : 10 }),
+        sim.order_from("Metro Wholesale".to_owned(), "Patty".to_owned(), 10)
+    );
+    assert_eq!(
+        Err(SimError::NotSold { supplier : "Green Valley Farm".to_owned(), ingredient : "Cup".to_owned() }),
+        sim.order_from("Green Valley Farm".to_owned(), "Cup".to_owned(), 50)
+    );
+    assert_eq!(
+        Err(SimError::UnknownSupplier("Acme".to_owned())),
+        sim.order_from("Acme".to_owned(), "Cup".to_owned(), 50)
+    );
+
+    //200 cups from the wholesaler get the bulk discount and arrive in 2 days
+    sim.order_from("Metro Wholesale".to_owned(), "Cup".to_owned(), 200).unwrap();
+    //200 cups at 6 cents are $12.00, less 15% is $10.20
+    assert_eq!(Money::from_cents(98980), sim.get_cash());
+    assert_eq!(1, sim.get_pending_orders().len());
+    assert_eq!(2, sim.get_pending_orders()[0].arrives_on);
+    assert_eq!(200, sim.get_inv("Cup".to_owned()));
+}
+
+#[test]
+fn delivery_test() {
+    let mut config = SimConfig::new("Test".to_owned());
+    config.rules.lose_on_unpaid_payroll = false;
+    let mut sim = Simulator::new(config);
+    sim.order_from("Metro Wholesale".to_owned(), "Syrup".to_owned(), 100).unwrap();
+    let mut arrived = 0;
+    for _ in 0..10 {
+        let report = sim.sim_day();
+        for delivery in &report.deliveries {
+            match delivery {
+                Delivery::Arrived(order) => arrived += order.quantity,
+                Delivery::Partial { delivered, .. } => arrived += delivered,
+                Delivery::Late(order) => assert!(order.arrives_on > report.day),
+            }
+            assert!(report.day >= 2);
+        }
+    }
+    assert!(sim.get_pending_orders().is_empty());
+    assert!((50..=100).contains(&arrived));
+}
+
+#[test]
+fn batch_quality_served_test() {
+    let mut config = SimConfig::new("Test".to_owned());
+    config.seed = 7;
+    let mut sim = Simulator::new(config);
+    //Replace the starting stock of buns with better ones
+    sim.restaurant.reduce_inv("Bun".to_owned(), 100);
+    sim.set_ingredient_quality("Bun".to_owned(), 3).unwrap();
+    sim.set_ingredient_quality("Patty".to_owned(), 3).unwrap();
+    sim.order_inv("Bun".to_owned(), 50).unwrap();
+    //Patties on hand are still quality 1, so that is what customers get
+    assert_eq!(1.0, sim.get_item_quality("Burger".to_owned()));
+    let report = sim.sim_day();
+    assert!(report.get_sold("Burger") > 0);
+    assert_eq!(1.0, report.get_quality_served("Burger"));
+}
@@ -0,0 +1,136 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+use super::restaurant::bank::Payment;
+use super::restaurant::capacity::Stage;
+use super::restaurant::employee::{Employee, Progress};
+use super::restaurant::ledger::Account;
+use super::restaurant::reputation::Review;
+use super::restaurant::supplier::Delivery;
+use super::restaurant::{LostReason, SegmentService, SlotService};
+use super::{GameOver, Money};
+
+// The following is the summary of a single simulated day.
+// The simulator fills one in every time a day is simulated,
+// and it is up to the front end to decide how to show it.
+
+pub struct DayReport {
+    //The day that was just simulated
+    pub day: i64,
+    //Seed the game was started from, so the run can be replayed
+    pub seed: u64,
+    //Day of the week, 0 is Monday
+    pub weekday: i64,
+    //Employees that quit at the start of the day, see Employee::update_morale
+    pub quit: Vec<Employee>,
+    //Employees whose rating went up at the end of the day, see Employee::learn
+    pub progress: Vec<Progress>,
+    //Orders that were due at the start of the day
+    pub deliveries: Vec<Delivery>,
+    pub customers_served: i64,
+    //Customers every stage of service could handle over the whole day, see Capacity
+    pub capacity: Vec<(Stage, i64)>,
+    //The stage that turned away the most customers,
+    //or the one that could handle the fewest if nobody was turned away
+    pub bottleneck: Stage,
+    //People that came in, were served and what they spent during every time slot
+    pub slots: Vec<SlotService>,
+    //People that came in, were served and what they spent from every customer segment
+    pub segments: Vec<SegmentService>,
+    //Reviews left by the parties served
+    pub reviews: Vec<Review>,
+    //Star rating of the restaurant at the end of the day, see Reputation
+    pub rating: f64,
+    //People that left without buying anything, for every reason
+    pub turned_away: Vec<(LostReason, i64)>,
+    //Orders of every menu item missed because it was sold out, in menu order
+    pub stockouts: Vec<(String, i64)>,
+    //Orders of every menu item missed because the customer couldn't afford it, in menu order
+    pub priced_out: Vec<(String, i64)>,
+    //Units sold of every menu item, in menu order
+    pub items_sold: Vec<(String, i64)>,
+    //Average quality customers received of every menu item, 0 if none were sold
+    pub quality_served: Vec<(String, f64)>,
+    //Units of every ingredient used up, in stock order
+    pub ingredients_used: Vec<(String, i64)>,
+    //Units of every ingredient thrown away because they spoiled, in stock order
+    pub spoiled: Vec<(String, i64)>,
+    //What the spoiled stock cost to buy
+    pub waste_cost: Money,
+    //Money taken in from customers, not counting sales tax
+    pub sales: Money,
+    //Sales tax customers paid on top of the sales
+    pub sales_tax: Money,
+    //What the stock used to make the items sold cost to buy
+    pub cost_of_goods: Money,
+    pub labor_cost: Money,
+    //Hours of the labor cost paid as overtime
+    pub overtime_hours: i64,
+    //Rent, utilities and insurance paid, see overhead
+    pub overhead: Vec<(Account, Money)>,
+    //Sales tax paid over and income tax paid
+    pub taxes: Vec<(Account, Money)>,
+    //Loan and credit line payments that came due, paid or missed, see Bank
+    pub payments: Vec<Payment>,
+    //Everything owed to the bank at the end of the day
+    pub debt: Money,
+    //Sales less cost of goods, labor, waste, overhead, interest and income tax, see Ledger
+    pub profit: Money,
+    //Change in cash over the day
+    pub cash_flow: Money,
+    //Set once the game has been won or lost
+    pub game_over: Option<GameOver>,
+}
+
+impl DayReport {
+    //Customers that left without buying anything for the given reason
+    pub fn get_turned_away(&self, reason: LostReason) -> i64 {
+        self.turned_away
+            .iter()
+            .find(|(other, _)| *other == reason)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    //Units sold of the given menu item
+    pub fn get_sold(&self, name: &str) -> i64 {
+        self.items_sold
+            .iter()
+            .find(|(item, _)| item == name)
+            .map_or(0, |(_, sold)| *sold)
+    }
+
+    //Average quality customers received of the given menu item
+    pub fn get_quality_served(&self, name: &str) -> f64 {
+        self.quality_served
+            .iter()
+            .find(|(item, _)| item == name)
+            .map_or(0.0, |(_, quality)| *quality)
+    }
+
+    //Units used of the given ingredient
+    pub fn get_used(&self, name: &str) -> i64 {
+        self.ingredients_used
+            .iter()
+            .find(|(ingredient, _)| ingredient == name)
+            .map_or(0, |(_, used)| *used)
+    }
+
+    //Amount paid to the given overhead or tax account
+    pub fn get_paid(&self, account: Account) -> Money {
+        self.overhead
+            .iter()
+            .chain(&self.taxes)
+            .find(|(other, _)| *other == account)
+            .map_or(Money::ZERO, |(_, amount)| *amount)
+    }
+
+    //Units of the given ingredient that spoiled
+    pub fn get_spoiled(&self, name: &str) -> i64 {
+        self.spoiled
+            .iter()
+            .find(|(ingredient, _)| ingredient == name)
+            .map_or(0, |(_, spoiled)| *spoiled)
+    }
+}
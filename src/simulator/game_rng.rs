@@ -0,0 +1,56 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following is the one random number generator used by the whole game.
+// The simulator owns it and hands it to everything that makes a random decision,
+// so two games started from the same seed play out exactly the same way.
+// It is a small splitmix64 generator, which keeps its whole state in one number
+// so it can be saved along with the rest of the game.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GameRng {
+    state: u64,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> GameRng {
+        GameRng { state: seed }
+    }
+}
+
+impl Rng for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[test]
+fn same_seed_test() {
+    let mut first = GameRng::new(42);
+    let mut second = GameRng::new(42);
+    for _ in 0..100 {
+        assert_eq!(first.gen_range(0, 1000), second.gen_range(0, 1000));
+    }
+}
+
+#[test]
+fn different_seed_test() {
+    let mut first = GameRng::new(1);
+    let mut second = GameRng::new(2);
+    let first_rolls: Vec<u64> = (0..10).map(|_| first.next_u64()).collect();
+    let second_rolls: Vec<u64> = (0..10).map(|_| second.next_u64()).collect();
+    assert_ne!(first_rolls, second_rolls);
+}
@@ -0,0 +1,266 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following is an amount of money, kept as a whole number of cents.
+// Prices, wages, balances and everything in the ledger are Money,
+// so adding up a day of sales never drifts by a fraction of a cent.
+// Arithmetic is checked: an amount too large to hold panics instead of wrapping around.
+// The checked_ functions hand back None instead for callers that can recover.
+// Money is only turned into a float to work out chances, see demand.
+// Money prints with a dollar sign and two decimals, e.g. $2.50 or -$12.34,
+// and parses from what a player types, e.g. 2.50, 2.5, 3 or $2.50.
+// Saves store Money as its number of cents.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Money {
+    cents: i64,
+}
+
+// Why a piece of text isn't an amount of money
+#[derive(Clone, PartialEq, Debug)]
+pub enum ParseMoneyError {
+    //Not a number of dollars and cents at all
+    Invalid(String),
+    //More than two decimal places, e.g. 2.505
+    TooPrecise(String),
+    //Too large to hold
+    Overflow(String),
+}
+
+impl fmt::Display for ParseMoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseMoneyError::Invalid(text) => write!(f, "'{}' is not an amount of money", text),
+            ParseMoneyError::TooPrecise(text) => write!(f, "'{}' has more than two decimal places", text),
+            ParseMoneyError::Overflow(text) => write!(f, "'{}' is too large", text),
+        }
+    }
+}
+
+impl Money {
+    pub const ZERO: Money = Money { cents: 0 };
+
+    pub const fn from_cents(cents: i64) -> Money {
+        Money { cents }
+    }
+
+    pub fn from_dollars(dollars: i64) -> Money {
+        Money::from_cents(dollars.checked_mul(100).expect("money overflowed"))
+    }
+
+    //Rounds an amount in dollars to the nearest cent
+    //Used for amounts read from a menu file and amounts worked out from rates
+    pub fn from_f64(dollars: f64) -> Money {
+        Money::checked_from_f64(dollars).expect("money overflowed")
+    }
+
+    //Like from_f64, but None if the amount isn't a number or is too large to hold
+    pub fn checked_from_f64(dollars: f64) -> Option<Money> {
+        let cents = (dollars * 100.0).round();
+        if !cents.is_finite() || cents.abs() >= i64::MAX as f64 {
+            return None;
+        }
+        Some(Money::from_cents(cents as i64))
+    }
+
+    //Reads an amount typed by a player, e.g. 2.50, 2.5, 3, -1.25 or $2.50
+    //Exact, the text is never turned into a float
+    pub fn parse(text: &str) -> Result<Money, ParseMoneyError> {
+        let trimmed = text.trim();
+        let invalid = || ParseMoneyError::Invalid(text.to_owned());
+        let (negative, rest) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+        let rest = rest.strip_prefix('$').unwrap_or(rest);
+        let (dollars, cents) = match rest.split_once('.') {
+            Some((dollars, cents)) => (dollars, cents),
+            None => (rest, ""),
+        };
+        let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        if (dollars.is_empty() && cents.is_empty()) || !is_digits(dollars) || !is_digits(cents) {
+            return Err(invalid());
+        }
+        if cents.len() > 2 {
+            return Err(ParseMoneyError::TooPrecise(text.to_owned()));
+        }
+        let overflow = || ParseMoneyError::Overflow(text.to_owned());
+        let dollars: i64 = if dollars.is_empty() { 0 } else { dollars.parse().map_err(|_| overflow())? };
+        let cents: i64 = format!("{:0<2}", cents).parse().map_err(|_| invalid())?;
+        let total = dollars
+            .checked_mul(100)
+            .and_then(|total| total.checked_add(cents))
+            .ok_or_else(overflow)?;
+        Ok(Money::from_cents(if negative { -total } else { total }))
+    }
+
+    //Getters
+    pub fn get_cents(self) -> i64 {
+        self.cents
+    }
+
+    //The amount in dollars, for working out chances and ratios
+    pub fn to_f64(self) -> f64 {
+        self.cents as f64 / 100.0
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.cents < 0
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.cents == 0
+    }
+
+    pub fn abs(self) -> Money {
+        Money::from_cents(self.cents.checked_abs().expect("money overflowed"))
+    }
+
+    //Checked arithmetic, None if the result is too large to hold
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        self.cents.checked_add(other.cents).map(Money::from_cents)
+    }
+
+    pub fn checked_sub(self, other: Money) -> Option<Money> {
+        self.cents.checked_sub(other.cents).map(Money::from_cents)
+    }
+
+    pub fn checked_mul(self, times: i64) -> Option<Money> {
+        self.cents.checked_mul(times).map(Money::from_cents)
+    }
+
+    //The amount without a dollar sign, e.g. 2.50 or -12.34, as parse reads it
+    pub fn to_decimal_string(self) -> String {
+        let sign = if self.cents < 0 { "-" } else { "" };
+        let cents = self.cents.unsigned_abs();
+        format!("{}{}.{:02}", sign, cents / 100, cents % 100)
+    }
+
+    //Scales the amount by a rate, such as overtime pay, rounded to the nearest cent
+    pub fn mul_f64(self, rate: f64) -> Money {
+        Money::from_f64(self.to_f64() * rate)
+    }
+
+    //Like mul_f64, but None if the result is too large to hold
+    pub fn checked_mul_f64(self, rate: f64) -> Option<Money> {
+        Money::checked_from_f64(self.to_f64() * rate)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.cents < 0 { "-" } else { "" };
+        f.pad(&format!("{}${}", sign, self.abs().to_decimal_string()))
+    }
+}
+
+impl FromStr for Money {
+    type Err = ParseMoneyError;
+
+    fn from_str(text: &str) -> Result<Money, ParseMoneyError> {
+        Money::parse(text)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        self.checked_add(other).expect("money overflowed")
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        self.checked_sub(other).expect("money overflowed")
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, other: Money) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money::from_cents(self.cents.checked_neg().expect("money overflowed"))
+    }
+}
+
+impl Mul<i64> for Money {
+    type Output = Money;
+
+    fn mul(self, times: i64) -> Money {
+        self.checked_mul(times).expect("money overflowed")
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, |tot, amount| tot + amount)
+    }
+}
+
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, |tot, amount| tot + *amount)
+    }
+}
+
+#[test]
+fn parse_test() {
+    assert_eq!(Ok(Money::from_cents(250)), Money::parse("2.50"));
+    assert_eq!(Ok(Money::from_cents(250)), Money::parse("2.5"));
+    assert_eq!(Ok(Money::from_cents(300)), Money::parse(" 3 "));
+    assert_eq!(Ok(Money::from_cents(5)), Money::parse("$.05"));
+    assert_eq!(Ok(Money::from_cents(-125)), Money::parse("-1.25"));
+    assert_eq!(Err(ParseMoneyError::TooPrecise("2.505".to_owned())), Money::parse("2.505"));
+    assert!(Money::parse("two").is_err());
+    assert!(Money::parse(".").is_err());
+    assert!(Money::parse("1.2.3").is_err());
+    assert!(Money::parse("99999999999999999999").is_err());
+}
+
+#[test]
+fn display_test() {
+    assert_eq!("$2.50", Money::from_cents(250).to_string());
+    assert_eq!("-$12.34", Money::from_cents(-1234).to_string());
+    assert_eq!("$0.05", Money::from_cents(5).to_string());
+    assert_eq!("   $1.00", format!("{:>8}", Money::from_dollars(1)));
+    assert_eq!("-0.05", Money::from_cents(-5).to_decimal_string());
+}
+
+#[test]
+fn arithmetic_test() {
+    //A tenth of a dollar added up a thousand times is exactly a hundred dollars
+    let total: Money = (0..1000).map(|_| Money::from_cents(10)).sum();
+    assert_eq!(Money::from_dollars(100), total);
+    assert_eq!(Money::from_cents(1088), Money::from_cents(725).mul_f64(1.5));
+    assert_eq!(Money::from_cents(5), Money::from_f64(100.0 * 0.0005));
+    assert_eq!(None, Money::from_cents(i64::MAX).checked_add(Money::from_cents(1)));
+    assert_eq!(None, Money::checked_from_f64(1e30));
+    assert_eq!(None, Money::checked_from_f64(f64::NAN));
+    assert_eq!(Some(Money::from_cents(1020)), Money::from_dollars(12).checked_mul_f64(0.85));
+    assert_eq!(None, Money::from_cents(i64::MAX).checked_mul_f64(2.0));
+    assert_eq!(Money::from_cents(-50), Money::from_cents(100) - Money::from_cents(150));
+}
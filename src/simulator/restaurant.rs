@@ -0,0 +1,1597 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+pub mod employee;
+use employee::{Employee, Progress, COOK, SERVER, WASHER};
+pub mod menu_item;
+use menu_item::MenuItem;
+pub mod ingredient;
+use ingredient::Ingredient;
+pub mod supplier;
+use supplier::{Delivery, PendingOrder, Supplier};
+pub mod menu_file;
+use menu_file::Menu;
+pub mod capacity;
+use capacity::{Capacity, Stage};
+pub mod demand;
+pub mod schedule;
+use schedule::{Shift, Slot, SHIFTS, SLOTS};
+pub mod segment;
+use segment::Segment;
+pub mod reputation;
+use reputation::{Reputation, Review, Visit};
+pub mod ledger;
+use ledger::{Account, Category, Ledger};
+pub mod bank;
+use bank::{Bank, Debt, Payment, LATE_FEE, RATE_DAYS};
+pub mod overhead;
+use crate::simulator::Money;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+const MIN_CUST: i64 = 5;
+const MAX_CUST: i64 = 25;
+//Lowest and highest taste of a customer, how much more or less than average they will pay
+const MIN_TASTE: f64 = 0.8;
+const MAX_TASTE: f64 = 1.2;
+//Fewest and most minutes a customer waits in line before walking out
+const MIN_PATIENCE: i64 = 10;
+const MAX_PATIENCE: i64 = 45;
+
+// Why a customer didn't get what they came for
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LostReason {
+    //Nobody could seat them before they ran out of patience, so they walked out
+    Capacity,
+    //The restaurant didn't have the ingredients to make what they ordered
+    Stockout,
+    //They thought what they ordered cost too much, or couldn't afford it
+    Price,
+}
+
+// Every reason in the order they are reported
+pub const LOST_REASONS: [LostReason; 3] = [LostReason::Capacity, LostReason::Stockout, LostReason::Price];
+
+impl LostReason {
+    pub fn get_description(self) -> String {
+        match self {
+            LostReason::Capacity => "walked out before they could be seated".to_owned(),
+            LostReason::Stockout => "found what they wanted sold out".to_owned(),
+            LostReason::Price => "thought the prices were too high".to_owned(),
+        }
+    }
+}
+
+// Used to represent a customer.
+// A customer is a party of one or more people from the same segment, see segment,
+// that come in, wait, order and pay together.
+// A customer has some amount of cash randomly generated on creation from their segment's budget.
+// The main purpose of a customer is to order.
+// On creation every person in the party picks what they want from the menu:
+// one item from the first category on the menu, and one item from each other category half of the time,
+// more or less often for the items and categories their segment likes.
+// The customer orders each item if they can afford it and think it is worth the price,
+// see demand, then their cash is reduced by the price.
+// The customer remembers the quality of every item they received.
+// Every customer comes in during one time slot of the day and waits in line to be seated,
+// but only for as many minutes as their patience allows.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Customer {
+    cash: Money,
+    //Name of the segment the customer belongs to
+    segment: String,
+    //Number of people in the party
+    party: i64,
+    //How much more or less than an average customer they will pay, 1 is average
+    taste: f64,
+    slot: Slot,
+    //Minutes the customer will wait in line before walking out
+    patience: i64,
+    //Minutes the customer waited in line before being seated
+    wait: i64,
+    orders: Vec<String>,
+    //Items bought and the quality each one was made at
+    received: Vec<(String, i64)>,
+}
+
+impl Customer {
+    pub fn new<R: Rng>(rng: &mut R, menu: &[MenuItem], segment: &Segment, party: i64, slot: Slot) -> Customer {
+        let mut orders: Vec<String> = Vec::new();
+        let mut categories: Vec<String> = Vec::new();
+        let mut cash = Money::ZERO;
+
+        for item in menu {
+            if !categories.contains(&item.get_category()) {
+                categories.push(item.get_category());
+            }
+        }
+
+        for _ in 0..party {
+            cash += segment.pick_budget(rng);
+            for (i, category) in categories.iter().enumerate() {
+                if i > 0 && rng.gen_range(0.0, 1.0) >= 0.5 * segment.get_like("", category) {
+                    continue;
+                }
+                let choices: Vec<&MenuItem> = menu
+                    .iter()
+                    .filter(|item| item.get_category() == *category)
+                    .collect();
+                //Items the segment likes more are picked more often, unless they like none of them
+                let likes: Vec<f64> = choices
+                    .iter()
+                    .map(|item| segment.get_like(&item.get_name(), ""))
+                    .collect();
+                let choice = match segment::pick_weighted(&likes, rng) {
+                    Some(choice) => choice,
+                    None => rng.gen_range(0, choices.len()),
+                };
+                orders.push(choices[choice].get_name());
+            }
+        }
+
+        Customer {
+            cash,
+            segment: segment.get_name(),
+            party,
+            taste: rng.gen_range(MIN_TASTE, MAX_TASTE),
+            slot,
+            patience: rng.gen_range(MIN_PATIENCE, MAX_PATIENCE + 1),
+            wait: 0,
+            orders,
+            received: Vec::new(),
+        }
+    }
+    //Getters
+    pub fn get_orders(&self) -> &[String] {
+        &self.orders
+    }
+
+    pub fn get_received(&self) -> &[(String, i64)] {
+        &self.received
+    }
+
+    pub fn get_segment(&self) -> String {
+        self.segment.to_owned()
+    }
+
+    pub fn get_party(&self) -> i64 {
+        self.party
+    }
+
+    pub fn get_slot(&self) -> Slot {
+        self.slot
+    }
+
+    pub fn get_patience(&self) -> i64 {
+        self.patience
+    }
+
+    pub fn get_wait(&self) -> i64 {
+        self.wait
+    }
+
+    // Order the given item from the menu.
+    // Menu item is given by name.
+    // Then it is checked if the restaurant has the ingredients to make it,
+    // if the customer has enough money to buy the item and if they think it is worth the price.
+    // The better the item and the lower the price, the more likely they are to buy it.
+    // If so then they will purchase the item and their cash will be reduced by the price and the sales tax on it.
+    // An item whose price with tax is too large to hold is too expensive for anyone.
+    // The restaurant then uses up the ingredients in the recipe and records the sale on the given day.
+    // Returns the quality of the item the customer received, or why they didn't buy it.
+    pub fn order<R: Rng>(&mut self, restaurant : &mut Restaurant, name : String, day : i64, rng : &mut R) -> Result<i64, LostReason> {
+        let price = restaurant.get_price(name.clone());
+        if restaurant.get_servings(name.clone()) <= 0 {
+            return Err(LostReason::Stockout);
+        }
+        let total = overhead::calc_total(price).ok_or(LostReason::Price)?;
+        if self.cash < total || rng.gen_range(0.0, 1.0) >= restaurant.calc_customer_chance(name.clone(), self) {
+            return Err(LostReason::Price);
+        }
+        self.cash -= total;
+        let quality = restaurant.sell_item(name.clone(), price, day);
+        self.received.push((name, quality));
+        Ok(quality)
+    }
+}
+
+// What came of serving a day's customers
+pub struct Service {
+    //Money taken in from customers
+    pub sales: Money,
+    //Units sold of every menu item, in menu order
+    pub items_sold: Vec<(String, i64)>,
+    //Average quality of every menu item sold, in menu order, 0 if none were sold
+    pub quality_served: Vec<(String, f64)>,
+    //Money taken in during every time slot, in slot order
+    pub slot_sales: Vec<(Slot, Money)>,
+    //Money taken in from every segment, in segment order
+    pub segment_sales: Vec<(String, Money)>,
+    //Orders of every menu item that couldn't be made, in menu order
+    pub stockouts: Vec<(String, i64)>,
+    //Orders of every menu item the customer couldn't afford, in menu order
+    pub priced_out: Vec<(String, i64)>,
+    //People that left without buying anything because of sold out items or prices
+    pub left_stockout: i64,
+    pub left_price: i64,
+    //Reviews left by the parties served, see reputation
+    pub reviews: Vec<Review>,
+}
+
+// How the customers that came in during one time slot were handled
+#[derive(Clone)]
+pub struct SlotService {
+    pub slot: Slot,
+    //People that came in
+    pub wanted: i64,
+    //People that were seated, the rest walked out of the line
+    pub served: i64,
+    //Average minutes the seated parties waited in line
+    pub avg_wait: f64,
+    //Money taken in from the customers served
+    pub sales: Money,
+    pub capacity: Capacity,
+}
+
+// How the people from one customer segment were handled over a day
+#[derive(Clone)]
+pub struct SegmentService {
+    pub segment: String,
+    //People that came in
+    pub wanted: i64,
+    //People that were seated, the rest walked out of the line
+    pub served: i64,
+    //Money taken in from the people served
+    pub sales: Money,
+}
+
+// Stock thrown away at the end of a day because it spoiled
+pub struct Waste {
+    //Units thrown away of every ingredient, in stock order
+    pub spoiled: Vec<(String, i64)>,
+    //What the thrown away stock cost to buy
+    pub cost: Money,
+}
+
+// The restaurant does most of the work for this program
+// The restaurant is in charge of creating and managing the list of potential and hired employees.
+// This includes the adding and removal of employees from said lists.
+// As well the restaurant handles the list of customers, menu items and the ingredient stock.
+// The restaurant can update any menu item's price and the quality of any ingredient.
+// A menu item is only as good as the worst ingredient in it.
+// Ingredients are bought from suppliers, and orders wait in a list until they arrive.
+// The restaurant also simulates serving the customers, for each day.
+// Every sale, payment and use of stock is written down in the ledger,
+// and the restaurant's balance is the cash in the ledger.
+// The restaurant can borrow from the bank, see bank.
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Restaurant {
+    name: String,
+    //The restaurant's books, see ledger
+    ledger: Ledger,
+    //Loans and credit line owed, see bank
+    bank: Bank,
+    id: i64,
+    menu: Vec<MenuItem>,
+    ingredients: Vec<Ingredient>,
+    suppliers: Vec<Supplier>,
+    //The kinds of customers that come in, see segment
+    segments: Vec<Segment>,
+    //What customers have said about the restaurant
+    reputation: Reputation,
+    pending_orders: Vec<PendingOrder>,
+    hired_empls: Vec<Employee>,
+    pot_empls: Vec<Employee>,
+    customers: Vec<Customer>,
+}
+
+impl Restaurant {
+    pub fn new<R: Rng>(name: String, menu: Menu, rng: &mut R) -> Restaurant {
+        let mut hired_empls: Vec<Employee> = Vec::new();
+        let mut pot_empls: Vec<Employee> = Vec::new();
+        let customers: Vec<Customer> = Vec::new();
+        let mut id = 0;
+
+        hired_empls.push(Employee::default(COOK, id + 1, rng));
+        id += 1;
+        hired_empls.push(Employee::default(SERVER, id + 1, rng));
+        id += 1;
+        hired_empls.push(Employee::default(WASHER, id + 1, rng));
+        id += 1;
+
+        for _ in 0..10 {
+            pot_empls.push(Employee::rand_empl(rng));
+        }
+
+        //The owner starts the restaurant with some cash and the stock the menu comes with
+        let mut ledger = Ledger::new();
+        ledger.record(0, Category::Capital, Account::Cash, Account::Equity, Money::from_dollars(1000), "Starting cash".to_owned());
+        let stock_value = menu.ingredients.iter().map(|ingredient| ingredient.get_value()).sum();
+        ledger.record(0, Category::Capital, Account::Inventory, Account::Equity, stock_value, "Starting stock".to_owned());
+
+        Restaurant {
+            name,
+            ledger,
+            bank: Bank::new(),
+            id,
+            menu: menu.items,
+            ingredients: menu.ingredients,
+            suppliers: menu.suppliers,
+            segments: menu.segments,
+            reputation: Reputation::new(),
+            pending_orders: Vec::new(),
+            hired_empls,
+            pot_empls,
+            customers,
+        }
+    }
+
+    //Getters
+    pub fn get_name(&self) -> String {
+        self.name.to_owned()
+    }
+
+    //Cash on hand
+    pub fn get_cash(&self) -> Money {
+        self.ledger.get_balance(Account::Cash)
+    }
+
+    pub fn get_ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    pub fn get_bank(&self) -> &Bank {
+        &self.bank
+    }
+
+    //Everything owed to the bank
+    pub fn get_debt(&self) -> Money {
+        self.bank.get_debt()
+    }
+
+    //What the stock on hand cost to buy
+    pub fn get_stock_value(&self) -> Money {
+        self.ingredients.iter().map(|ingredient| ingredient.get_value()).sum()
+    }
+
+    pub fn get_menu(&self) -> &[MenuItem] {
+        &self.menu
+    }
+
+    pub fn get_ingredients(&self) -> &[Ingredient] {
+        &self.ingredients
+    }
+
+    pub fn get_suppliers(&self) -> &[Supplier] {
+        &self.suppliers
+    }
+
+    pub fn get_supplier(&self, name: &str) -> Option<&Supplier> {
+        self.suppliers.iter().find(|supplier| supplier.get_name() == name)
+    }
+
+    pub fn get_segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    pub fn get_segment(&self, name: &str) -> Option<&Segment> {
+        self.segments.iter().find(|segment| segment.get_name() == name)
+    }
+
+    pub fn get_reputation(&self) -> &Reputation {
+        &self.reputation
+    }
+
+    pub fn get_pending_orders(&self) -> &[PendingOrder] {
+        &self.pending_orders
+    }
+
+    pub fn get_hired_empls(&self) -> &[Employee] {
+        &self.hired_empls
+    }
+
+    pub fn get_pot_empls(&self) -> &[Employee] {
+        &self.pot_empls
+    }
+
+    pub fn get_hired_empl(&self, position: usize) -> &Employee {
+        &self.hired_empls[position]
+    }
+
+    pub fn get_pot_empl(&self, position: usize) -> Employee {
+        self.pot_empls[position].clone()
+    }
+
+    //Getters for a given MenuItem
+    //Item selected based on given name
+    pub fn get_price(&self, name: String) -> Money {
+        let mut price = Money::ZERO;
+
+        for item in &self.menu {
+            if name == item.get_name() {
+                price = item.get_price()
+            }
+        }
+
+        price
+    }
+
+    //Quality of a menu item is the lowest quality of its ingredients on hand
+    //An ingredient that is out of stock counts at the quality it is bought at
+    pub fn get_item_quality(&self, name: String) -> f64 {
+        let mut quality: f64 = 0.0;
+
+        for item in &self.menu {
+            if name == item.get_name() {
+                quality = item
+                    .get_recipe()
+                    .iter()
+                    .map(|(name, _)| {
+                        self.ingredients
+                            .iter()
+                            .find(|ingredient| ingredient.get_name() == *name)
+                            .map_or(0.0, |ingredient| {
+                                ingredient
+                                    .get_stock_quality()
+                                    .unwrap_or(ingredient.get_quality() as f64)
+                            })
+                    })
+                    .fold(f64::INFINITY, f64::min);
+                if quality.is_infinite() {
+                    quality = 0.0;
+                }
+            }
+        }
+        quality
+    }
+
+    //Number of the given menu item that can be made from the ingredients in stock
+    pub fn get_servings(&self, name: String) -> i64 {
+        let mut servings: i64 = 0;
+
+        for item in &self.menu {
+            if name == item.get_name() {
+                servings = item
+                    .get_recipe()
+                    .iter()
+                    .map(|(ingredient, amount)| self.get_inv(ingredient.clone()) / amount)
+                    .min()
+                    .unwrap_or(0);
+            }
+        }
+        servings
+    }
+
+    //Getters for a given Ingredient
+    //Ingredient selected based on given name
+    pub fn get_ingredient_quality(&self, name: String) -> i64 {
+        let mut quality: i64 = 0;
+
+        for ingredient in &self.ingredients {
+            if name == ingredient.get_name() {
+                quality = ingredient.get_quality();
+            }
+        }
+        quality
+    }
+
+    pub fn get_supply_cost(&self, name: String) -> Money {
+        let mut cost = Money::ZERO;
+
+        for ingredient in &self.ingredients {
+            if name == ingredient.get_name() {
+                cost = ingredient.get_supply_cost();
+            }
+        }
+        cost
+    }
+
+    pub fn get_inv(&self, name: String) -> i64 {
+        let mut inv: i64 = 0;
+
+        for ingredient in &self.ingredients {
+            if name == ingredient.get_name() {
+                inv = ingredient.get_inv();
+            }
+        }
+        inv
+    }
+
+
+    //Setters for a given MenuItem
+    //Item selected based on given name
+    //What customers expect to pay for the given menu item at quality 1
+    pub fn get_reference_price(&self, name: String) -> Money {
+        self.menu
+            .iter()
+            .find(|item| item.get_name() == name)
+            .map_or(Money::ZERO, |item| item.get_reference_price())
+    }
+
+    //Chance a customer from the given segment with the given taste buys the given menu item
+    //at its price and the quality of the stock on hand
+    pub fn calc_purchase_chance(&self, name: String, segment: &Segment, taste: f64) -> f64 {
+        segment.calc_purchase_chance(
+            self.get_price(name.clone()),
+            self.get_reference_price(name.clone()),
+            self.get_item_quality(name),
+            taste,
+        )
+    }
+
+    //Chance the given customer buys the given menu item
+    //A customer from a segment the restaurant doesn't know is treated as average
+    pub fn calc_customer_chance(&self, name: String, customer: &Customer) -> f64 {
+        match self.get_segment(&customer.segment) {
+            Some(segment) => self.calc_purchase_chance(name, segment, customer.taste),
+            None => self.calc_purchase_chance(name, &Segment::average(), customer.taste),
+        }
+    }
+
+    pub fn set_item_price(&mut self, name: String, new_price: Money) {
+        for item in &mut self.menu {
+            if name == item.get_name() {
+                item.set_price(new_price);
+                return;
+            }
+        }
+    }
+
+    //Setter for a given Ingredient
+    pub fn set_ingredient_quality(&mut self, name: String, new_quality: i64) {
+        for ingredient in &mut self.ingredients {
+            if name == ingredient.get_name() {
+                ingredient.set_quality(new_quality);
+                return;
+            }
+        }
+    }
+
+    //Generates a new list of potential employees
+    pub fn generate_pot_empls<R: Rng>(&mut self, rng: &mut R){
+        let mut new_pot_empls : Vec<Employee> = Vec::new();
+        for _ in 0..10 {
+            new_pot_empls.push(Employee::rand_empl(rng));
+        }
+        self.pot_empls = new_pot_empls;
+    }
+
+    // Hire
+    // Removes the given employee from the potential employees list.
+    // Then gives the employee a new id and adds it to the hired employee list
+    pub fn hire_emp(&mut self, mut new_emp: Employee) {
+        self.pot_empls.retain(|x| x.get_id() != new_emp.get_id());
+        self.id +=1;
+        new_emp.set_id(self.id);
+        self.hired_empls.push(new_emp);
+    }
+
+    //Fire
+    // Removes the employee whose id matches the given id, from the hired employees list.
+    pub fn fire_emp(&mut self, id: i64) {
+        self.hired_empls.retain(|x| x.get_id() != id);
+    }
+
+    //Pays out the given amount of cash on the given day
+    //The account says what the money went to, such as Payroll or Inventory
+    pub fn pay(&mut self, day: i64, category: Category, account: Account, amount: Money, memo: String) {
+        self.ledger.record(day, category, account, Account::Cash, amount, memo);
+    }
+
+    //Takes in the given amount of cash on the given day
+    //The account says where the money came from, such as Sales
+    pub fn receive(&mut self, day: i64, category: Category, account: Account, amount: Money, memo: String) {
+        self.ledger.record(day, category, Account::Cash, account, amount, memo);
+    }
+
+    //Weekly interest rate of a loan taken out on the given day
+    //Based on the net income and sales of the RATE_DAYS days before it
+    pub fn calc_loan_rate(&self, day: i64) -> f64 {
+        let statement = self.ledger.calc_statement(day - RATE_DAYS, day - 1);
+        bank::calc_rate(statement.net_income, statement.get_amount(Account::Sales))
+    }
+
+    //Most that can be borrowed as a new loan on the given day
+    pub fn calc_loan_limit(&self, day: i64) -> Money {
+        let statement = self.ledger.calc_statement(day - RATE_DAYS, day - 1);
+        self.bank.calc_loan_limit(statement.get_amount(Account::Sales))
+    }
+
+    //Borrows the given amount on the given day, paid back weekly over the given weeks
+    //Returns the id of the new loan
+    pub fn take_loan(&mut self, amount: Money, weeks: i64, day: i64) -> i64 {
+        let rate = self.calc_loan_rate(day);
+        let id = self.bank.take_loan(amount, rate, weeks, day);
+        self.receive(day, Category::Borrowing, Account::Loans, amount, Debt::Loan(id).get_name());
+        id
+    }
+
+    //Draws the given amount on the credit line on the given day
+    pub fn draw_credit(&mut self, amount: Money, day: i64) {
+        self.bank.draw(amount);
+        self.receive(day, Category::Borrowing, Account::CreditLine, amount, Debt::CreditLine.get_name());
+    }
+
+    //Pays back up to the given amount of the credit line on the given day
+    //Returns the amount paid
+    pub fn repay_credit(&mut self, amount: Money, day: i64) -> Money {
+        let paid = self.bank.repay(amount);
+        self.pay(day, Category::Repayment, Account::CreditLine, paid, Debt::CreditLine.get_name());
+        paid
+    }
+
+    //Collects the loan and credit line payments due on the given day out of the cash on hand
+    //A missed payment's interest and late fee are added to what is owed instead of paid
+    pub fn collect_payments(&mut self, day: i64) -> Vec<Payment> {
+        let credit_rate = bank::calc_credit_rate(self.calc_loan_rate(day));
+        let payments = self.bank.collect(day, self.get_cash(), credit_rate);
+        for payment in &payments {
+            let account = match payment.debt {
+                Debt::Loan(_) => Account::Loans,
+                Debt::CreditLine => Account::CreditLine,
+            };
+            let name = payment.debt.get_name();
+            if payment.missed {
+                let memo = format!("{} missed payment", name);
+                self.ledger.record(day, Category::Interest, Account::Interest, account, payment.interest + LATE_FEE, memo);
+            } else {
+                self.pay(day, Category::Interest, Account::Interest, payment.interest, name.clone());
+                self.pay(day, Category::Repayment, account, payment.principal, name);
+            }
+        }
+        payments
+    }
+
+    //Pays the rent, utilities and insurance due on the given day, see overhead
+    //Returns what was paid to every account, in account order
+    pub fn pay_overhead(&mut self, day: i64, customers_served: i64) -> Vec<(Account, Money)> {
+        let mut costs = vec![(Account::Utilities, overhead::calc_utilities(customers_served))];
+        if overhead::is_week_end(day) {
+            costs.insert(0, (Account::Rent, overhead::RENT));
+        }
+        if overhead::is_month_start(day) {
+            costs.push((Account::Insurance, overhead::INSURANCE));
+        }
+        for (account, amount) in &costs {
+            self.pay(day, Category::Overhead, *account, *amount, account.get_name());
+        }
+        costs
+    }
+
+    //Pays the taxes due on the given day, see overhead
+    //The sales tax collected is paid over at the end of the week,
+    //and income tax on the month's profit at the end of the month
+    //Returns what was paid to every account
+    pub fn pay_taxes(&mut self, day: i64) -> Vec<(Account, Money)> {
+        let mut taxes: Vec<(Account, Money)> = Vec::new();
+        if overhead::is_week_end(day) {
+            taxes.push((Account::SalesTax, self.ledger.get_balance(Account::SalesTax)));
+        }
+        if overhead::is_month_end(day) {
+            let profit = self.ledger.calc_statement(day - ledger::MONTH_DAYS + 1, day).net_income;
+            taxes.push((Account::IncomeTax, overhead::calc_income_tax(profit)));
+        }
+        for (account, amount) in &taxes {
+            self.pay(day, Category::Tax, *account, *amount, account.get_name());
+        }
+        taxes.retain(|(_, amount)| *amount > Money::ZERO);
+        taxes
+    }
+
+    //Increment Inventory for given ingredient and increment by the given amount
+    //The new stock arrives on the given day
+    pub fn inc_inv(&mut self, name: String, inc_amount: i64, day: i64) {
+        for ingredient in &mut self.ingredients {
+            if name == ingredient.get_name() {
+                ingredient.inc_inv(inc_amount, day);
+                return;
+            }
+        }
+    }
+
+    //Decrement Inventory for given ingredient and decrement by the given amount
+    pub fn reduce_inv(&mut self, name: String, dec_amount: i64) {
+        for ingredient in &mut self.ingredients {
+            if name == ingredient.get_name() {
+                ingredient.dec_inv(dec_amount);
+            }
+        }
+    }
+
+    //Adds an order to the list of orders waiting to arrive
+    pub fn place_order(&mut self, order: PendingOrder) {
+        self.pending_orders.push(order);
+    }
+
+    //Puts a delivered order into stock as a new batch
+    pub fn stock_order(&mut self, order: &PendingOrder, quantity: i64, day: i64) {
+        for ingredient in &mut self.ingredients {
+            if order.ingredient == ingredient.get_name() {
+                ingredient.add_batch(quantity, order.quality, order.unit_cost, day);
+                return;
+            }
+        }
+    }
+
+    //Receives every order due on or before the given day
+    //Each supplier decides if its order arrives in full, in part or late
+    //Units that never arrive are refunded, late orders go back on the list
+    pub fn receive_orders<R: Rng>(&mut self, day: i64, rng: &mut R) -> Vec<Delivery> {
+        let (due, waiting): (Vec<PendingOrder>, Vec<PendingOrder>) =
+            std::mem::take(&mut self.pending_orders)
+                .into_iter()
+                .partition(|order| order.arrives_on <= day);
+        self.pending_orders = waiting;
+
+        let mut deliveries: Vec<Delivery> = Vec::new();
+        for order in due {
+            let delivery = match self.get_supplier(&order.supplier) {
+                Some(supplier) => supplier.deliver(order, rng),
+                None => Delivery::Arrived(order),
+            };
+            match &delivery {
+                Delivery::Arrived(order) => self.stock_order(order, order.quantity, day),
+                Delivery::Partial { order, delivered, refund } => {
+                    self.stock_order(order, *delivered, day);
+                    let memo = format!("{} {} from {}", order.quantity - delivered, order.ingredient, order.supplier);
+                    self.receive(day, Category::Refund, Account::Inventory, *refund, memo);
+                }
+                Delivery::Late(order) => self.pending_orders.push(order.clone()),
+            }
+            deliveries.push(delivery);
+        }
+        deliveries
+    }
+
+    //Throws away all the stock that spoils by the end of the given day
+    //What the stock cost is written off as waste
+    pub fn spoil_ingredients(&mut self, day: i64) -> Waste {
+        let mut spoiled: Vec<(String, i64)> = Vec::new();
+        let mut cost = Money::ZERO;
+
+        for ingredient in &mut self.ingredients {
+            let (units, units_cost) = ingredient.spoil(day);
+            spoiled.push((ingredient.get_name(), units));
+            cost += units_cost;
+            let memo = format!("{} {}", units, ingredient.get_name());
+            self.ledger.record(day, Category::Spoilage, Account::Waste, Account::Inventory, units_cost, memo);
+        }
+
+        Waste { spoiled, cost }
+    }
+
+    //Uses up the ingredients needed to make one of the given menu item
+    //Returns the quality the item was made at, the lowest quality of the stock it used
+    pub fn make_item(&mut self, name: String) -> i64 {
+        let recipe = match self.menu.iter().find(|item| item.get_name() == name) {
+            Some(item) => item.get_recipe().to_vec(),
+            None => return 0,
+        };
+        let mut quality = 3;
+        for (name, amount) in recipe {
+            for ingredient in &mut self.ingredients {
+                if name == ingredient.get_name() {
+                    if let Some(used_quality) = ingredient.dec_inv(amount) {
+                        quality = quality.min(used_quality);
+                    }
+                }
+            }
+        }
+        quality
+    }
+
+    //Makes one of the given menu item and sells it for the given price on the given day
+    //The sale and the sales tax collected on it are recorded, as is what the stock used to make it cost
+    //Returns the quality the item was made at
+    pub fn sell_item(&mut self, name: String, price: Money, day: i64) -> i64 {
+        let stock_value = self.get_stock_value();
+        let quality = self.make_item(name.clone());
+        let cost = stock_value - self.get_stock_value();
+        self.receive(day, Category::Sale, Account::Sales, price, name.clone());
+        self.receive(day, Category::Tax, Account::SalesTax, overhead::calc_sales_tax(price), name.clone());
+        self.ledger.record(day, Category::Usage, Account::CostOfGoods, Account::Inventory, cost, name);
+        quality
+    }
+
+    //Customers every stage of service can handle during the given time slot
+    //Every employee helps for the hours of their shifts that fall in the slot,
+    //except employees away on a training course
+    pub fn calc_capacity(&self, weekday: i64, slot: Slot) -> Capacity {
+        let mut staff: Vec<(&Employee, i64)> = Vec::new();
+        for empl in self.hired_empls.iter().filter(|empl| !empl.is_training()) {
+            let hours: i64 = SHIFTS
+                .iter()
+                .filter(|shift| empl.works(weekday, **shift))
+                .map(|shift| slot.get_overlap(*shift))
+                .sum();
+            if hours > 0 {
+                staff.push((empl, hours));
+            }
+        }
+        Capacity::new(&staff, slot.get_length())
+    }
+
+    //Customers every stage of service can handle over the whole day
+    pub fn calc_day_capacity(&self, weekday: i64) -> Capacity {
+        let slots: Vec<Capacity> = SLOTS
+            .iter()
+            .map(|slot| self.calc_capacity(weekday, *slot))
+            .collect();
+        Capacity::total(&slots)
+    }
+
+    //The stage that turned away the most customers over the given time slots
+    //None if every customer was served
+    pub fn find_bottleneck(slots: &[SlotService]) -> Option<Stage> {
+        let mut turned_away: Vec<(Stage, i64)> = Vec::new();
+        for slot in slots.iter().filter(|slot| slot.served < slot.wanted) {
+            let stage = slot.capacity.get_bottleneck().0;
+            match turned_away.iter_mut().find(|(other, _)| *other == stage) {
+                Some((_, count)) => *count += slot.wanted - slot.served,
+                None => turned_away.push((stage, slot.wanted - slot.served)),
+            }
+        }
+        let mut bottleneck: Option<(Stage, i64)> = None;
+        for (stage, count) in turned_away {
+            if bottleneck.map_or(true, |(_, most)| count > most) {
+                bottleneck = Some((stage, count));
+            }
+        }
+        bottleneck.map(|(stage, _)| stage)
+    }
+
+    //Customers the staff can serve in a day, decided by the bottleneck stage of every time slot
+    #[cfg(test)]
+    fn calc_cust_serv(&self, weekday: i64) -> i64 {
+        SLOTS
+            .iter()
+            .map(|slot| self.calc_capacity(weekday, *slot).get_customers())
+            .sum()
+    }
+
+    //Takes in the total quality and returns the customer modifier.
+    fn num_cust_helper(quality: i64) -> i64 {
+        match quality {
+            //Poor Quality
+            q if q <= 3 => 1,
+            //Medium Quality
+            4..=5 => 10,
+            //Higher Quality
+            6..=8 => 20,
+            //Highest in all qualities
+            _ => 25,
+        }
+    }
+
+    //Used to calculate the modifier for the amount of customers
+    //Uses the total quality of what is on hand to get a range from the helper function
+    //The total is scaled to a three item menu so menus of any size use the same ranges
+    pub fn num_cust_mod(&self) -> i64 {
+        let mut tot_quality = 0.0;
+
+        for item in &self.menu {
+            tot_quality += self.get_item_quality(item.get_name());
+        }
+
+        if !self.menu.is_empty() {
+            tot_quality = tot_quality * 3.0 / self.menu.len() as f64;
+        }
+
+        Restaurant::num_cust_helper(tot_quality.round() as i64)
+    }
+
+    //Generates the list of customers for a restaurant on the given day of the week.
+    //Number of people is determined based on quality of the items and the restaurant's reputation.
+    //Every party is from a segment picked following how many of each segment come in that day,
+    //comes in during a time slot the segment prefers, at a random minute of the slot,
+    //and waits in line to be seated.
+    //The staff working the slot seat one person every so many minutes, set by the slot's capacity,
+    //so customers that come in during a rush wait longer and may run out of patience and walk out.
+    //Nobody is seated after the slot ends, and a party is only seated if all of them can be.
+    //Returns how every slot and every segment was handled.
+    pub fn generate_customers<R: Rng>(&mut self, weekday: i64, rng: &mut R) -> (Vec<SlotService>, Vec<SegmentService>) {
+        let mut new_customers: Vec<Customer> = Vec::new();
+        let num_cust = rng.gen_range(
+            MIN_CUST + self.num_cust_mod(),
+            MAX_CUST + self.num_cust_mod(),
+        );
+        let num_cust = (num_cust as f64 * self.reputation.reputation_mod()).round() as i64;
+
+        let mut lines: Vec<Vec<Customer>> = vec![Vec::new(); SLOTS.len()];
+        let mut people = 0;
+        while people < num_cust {
+            let segment = match segment::pick(&self.segments, weekday, rng) {
+                Some(segment) => segment,
+                None => break,
+            };
+            //The last party is cut down to the people left to come in
+            let party = segment.pick_party(rng).min(num_cust - people);
+            let slot = segment.pick_slot(rng);
+            people += party;
+            if let Some(i) = SLOTS.iter().position(|other| *other == slot) {
+                lines[i].push(Customer::new(rng, &self.menu, segment, party, slot));
+            }
+        }
+
+        let mut segments: Vec<SegmentService> = self
+            .segments
+            .iter()
+            .map(|segment| SegmentService {
+                segment: segment.get_name(),
+                wanted: 0,
+                served: 0,
+                sales: Money::ZERO,
+            })
+            .collect();
+        let mut slots: Vec<SlotService> = Vec::new();
+        for (slot, line) in SLOTS.iter().zip(lines) {
+            let capacity = self.calc_capacity(weekday, *slot);
+            let minutes = slot.get_length() * 60;
+            let mut line: Vec<(i64, Customer)> = line
+                .into_iter()
+                .map(|customer| (rng.gen_range(0, minutes), customer))
+                .collect();
+            line.sort_by_key(|(arrives, _)| *arrives);
+
+            let mut wanted = 0;
+            let mut served = 0;
+            let mut parties = 0;
+            let mut tot_wait = 0;
+            //Minutes between seating one person and the next
+            let seat_every = minutes as f64 / capacity.get_customers().max(1) as f64;
+            let mut free_at: f64 = 0.0;
+            for (arrives, mut customer) in line {
+                wanted += customer.party;
+                if let Some(counts) = segments.iter_mut().find(|counts| counts.segment == customer.segment) {
+                    counts.wanted += customer.party;
+                }
+                let seated = free_at.max(arrives as f64);
+                let last_seated = seated + seat_every * (customer.party - 1) as f64;
+                let wait = (seated - arrives as f64).ceil() as i64;
+                if capacity.get_customers() <= 0 || last_seated >= minutes as f64 || wait > customer.patience {
+                    continue;
+                }
+                free_at = last_seated + seat_every;
+                customer.wait = wait;
+                tot_wait += wait;
+                served += customer.party;
+                parties += 1;
+                if let Some(counts) = segments.iter_mut().find(|counts| counts.segment == customer.segment) {
+                    counts.served += customer.party;
+                }
+                new_customers.push(customer);
+            }
+
+            slots.push(SlotService {
+                slot: *slot,
+                wanted,
+                served,
+                avg_wait: if parties > 0 { tot_wait as f64 / parties as f64 } else { 0.0 },
+                sales: Money::ZERO,
+                capacity,
+            });
+        }
+
+        self.customers = new_customers;
+        (slots, segments)
+    }
+
+    //Goes through the list of customers
+    //Every customer orders each item their party picked if they have enough money and think it is worth the price
+    //No orders will occur if there aren't enough ingredients to make the item
+    //Every customer then leaves a review of their visit on the given day
+    //Returns the total sales for the day and how many of each item were sold
+    pub fn serve_customers<R: Rng>(&mut self, day: i64, rng: &mut R) -> Service {
+        let mut tot_sales = Money::ZERO;
+        //Units sold and total quality of every item
+        let mut totals: Vec<(String, i64, i64)> =
+            self.menu.iter().map(|item| (item.get_name(), 0, 0)).collect();
+        let mut slot_sales: Vec<(Slot, Money)> = SLOTS.iter().map(|slot| (*slot, Money::ZERO)).collect();
+        let mut segment_sales: Vec<(String, Money)> =
+            self.segments.iter().map(|segment| (segment.get_name(), Money::ZERO)).collect();
+        let mut stockouts: Vec<(String, i64)> = self.menu.iter().map(|item| (item.get_name(), 0)).collect();
+        let mut priced_out = stockouts.clone();
+        let mut left_stockout = 0;
+        let mut left_price = 0;
+        let mut reviews: Vec<Review> = Vec::new();
+        let mut customers = std::mem::take(&mut self.customers);
+
+        for customer in &mut customers {
+            let mut customer_sales = Money::ZERO;
+            //Why the customer's first missed order was missed
+            let mut missed: Option<LostReason> = None;
+            let mut sold_out = 0;
+            let mut too_expensive = 0;
+            let mut tot_value = 0.0;
+            for name in customer.get_orders().to_vec() {
+                let price = self.get_price(name.clone());
+                match customer.order(self, name.clone(), day, rng) {
+                    Ok(quality) => {
+                        customer_sales += price;
+                        if price > Money::ZERO {
+                            tot_value += self.get_reference_price(name.clone()).to_f64() / price.to_f64();
+                        } else {
+                            tot_value += 2.0;
+                        }
+                        if let Some((_, sold, tot_quality)) = totals.iter_mut().find(|(item, _, _)| *item == name) {
+                            *sold += 1;
+                            *tot_quality += quality;
+                        }
+                    }
+                    Err(reason) => {
+                        if reason == LostReason::Stockout {
+                            sold_out += 1;
+                        } else {
+                            too_expensive += 1;
+                        }
+                        let counts = if reason == LostReason::Stockout { &mut stockouts } else { &mut priced_out };
+                        if let Some((_, count)) = counts.iter_mut().find(|(item, _)| *item == name) {
+                            *count += 1;
+                        }
+                        missed = missed.or(Some(reason));
+                    }
+                }
+            }
+            if customer.get_received().is_empty() {
+                match missed {
+                    Some(LostReason::Stockout) => left_stockout += customer.get_party(),
+                    Some(LostReason::Price) => left_price += customer.get_party(),
+                    _ => (),
+                }
+            }
+            if let Some((_, sales)) = slot_sales.iter_mut().find(|(slot, _)| *slot == customer.get_slot()) {
+                *sales += customer_sales;
+            }
+            if let Some((_, sales)) = segment_sales.iter_mut().find(|(segment, _)| *segment == customer.get_segment()) {
+                *sales += customer_sales;
+            }
+
+            tot_sales += customer_sales;
+
+            let received = customer.get_received();
+            let visit = Visit {
+                wait: customer.get_wait(),
+                patience: customer.get_patience(),
+                quality: if received.is_empty() {
+                    0.0
+                } else {
+                    received.iter().map(|(_, quality)| *quality as f64).sum::<f64>() / received.len() as f64
+                },
+                value: if received.is_empty() { 0.0 } else { tot_value / received.len() as f64 },
+                ordered: customer.get_orders().len() as i64,
+                sold_out,
+                too_expensive,
+            };
+            let review = Review {
+                day,
+                segment: customer.get_segment(),
+                stars: visit.calc_stars(),
+                text: visit.write_review(rng),
+            };
+            self.reputation.add_review(review.clone());
+            reviews.push(review);
+        }
+
+        self.customers = customers;
+        Service {
+            sales: tot_sales,
+            items_sold: totals.iter().map(|(name, sold, _)| (name.clone(), *sold)).collect(),
+            quality_served: totals
+                .iter()
+                .map(|(name, sold, tot_quality)| {
+                    let quality = if *sold > 0 { *tot_quality as f64 / *sold as f64 } else { 0.0 };
+                    (name.clone(), quality)
+                })
+                .collect(),
+            slot_sales,
+            segment_sales,
+            stockouts,
+            priced_out,
+            left_stockout,
+            left_price,
+            reviews,
+        }
+    }
+
+    //Calculate costs
+    //Goes through list of hired empls 
+    //Calculates cost of labor for the given day of the week for each employee
+    //Employees are paid for their scheduled hours, with overtime past 40 hours in the week
+    //Employees away on a training course are still paid for the shifts they miss
+    pub fn calc_empl_day_cost(&self, weekday: i64) -> Money {
+        let mut empl_cost = Money::ZERO;
+
+        for empl in &self.hired_empls {
+            empl_cost += empl.calc_day_pay(weekday);
+        }
+
+        empl_cost
+    }
+
+    //Hours of overtime worked by all employees on the given day of the week
+    pub fn calc_overtime(&self, weekday: i64) -> i64 {
+        self.hired_empls.iter().map(|empl| empl.get_overtime(weekday)).sum()
+    }
+
+    //Every employee works their shifts for the given day of the week
+    pub fn work_day(&mut self, weekday: i64) {
+        for empl in &mut self.hired_empls {
+            empl.work_day(weekday);
+        }
+    }
+
+    //Changes the morale of every employee at the end of the given day of the week
+    //An employee's workload is the people that came in during the time slots they worked
+    //over what their stage could handle in those slots
+    pub fn update_morale(&mut self, weekday: i64, slots: &[SlotService]) {
+        for empl in &mut self.hired_empls {
+            let stage = Stage::of_posit(empl.get_posit());
+            let mut wanted = 0;
+            let mut capacity = 0;
+            for slot in slots {
+                //Nothing is asked of employees away on a course
+                let worked = !empl.is_training() && SHIFTS
+                    .iter()
+                    .any(|shift| empl.works(weekday, *shift) && slot.slot.get_overlap(*shift) > 0);
+                if worked {
+                    wanted += slot.wanted;
+                    capacity += slot.capacity.get_stage(stage);
+                }
+            }
+            let load = if capacity > 0 {
+                wanted as f64 / capacity as f64
+            } else if wanted > 0 {
+                2.0
+            } else {
+                0.0
+            };
+            empl.update_morale(!empl.is_training() && empl.get_day_hours(weekday) > 0, load);
+        }
+    }
+
+    //Every employee learns from the given day of the week, see Employee::learn
+    //Returns how the employees that got better did
+    pub fn learn(&mut self, weekday: i64) -> Vec<Progress> {
+        self.hired_empls.iter_mut().filter_map(|empl| empl.learn(weekday)).collect()
+    }
+
+    //Pays for a training course for the given position and sends the hired employee at the given index on it
+    pub fn train_empl(&mut self, index: usize, posit: i64, day: i64) {
+        let memo = format!("{} {} course", self.hired_empls[index].get_name(), employee::get_posit_name(posit));
+        self.pay(day, Category::Training, Account::Training, employee::COURSE_COST, memo);
+        self.hired_empls[index].start_course(posit);
+    }
+
+    //Gives the hired employee at the given index the wage they asked for
+    //Returns the new wage, None if they haven't asked for one
+    pub fn grant_raise(&mut self, index: usize) -> Option<Money> {
+        let empl = &mut self.hired_empls[index];
+        let wage = empl.get_asked_wage()?;
+        empl.set_wage(wage);
+        Some(wage)
+    }
+
+    //Employees unhappy enough may quit, see Employee::calc_quit_chance
+    //Returns the employees that quit
+    pub fn quit_empls<R: Rng>(&mut self, rng: &mut R) -> Vec<Employee> {
+        let mut quit: Vec<Employee> = Vec::new();
+        let mut i = 0;
+        while i < self.hired_empls.len() {
+            let chance = self.hired_empls[i].calc_quit_chance();
+            if chance > 0.0 && rng.gen_range(0.0, 1.0) < chance {
+                quit.push(self.hired_empls.remove(i));
+            } else {
+                i += 1;
+            }
+        }
+        quit
+    }
+
+    //Starts a new week of hours for every employee
+    pub fn reset_week(&mut self) {
+        for empl in &mut self.hired_empls {
+            empl.reset_week();
+        }
+    }
+
+    //Adds a shift to the schedule of the hired employee at the given index
+    //Returns false if they already work it or it would go over their hours
+    pub fn add_shift(&mut self, index: usize, weekday: i64, shift: Shift) -> bool {
+        self.hired_empls[index].add_shift(weekday, shift)
+    }
+
+    //Takes a shift off the schedule of the hired employee at the given index
+    //Returns false if they didn't work it
+    pub fn remove_shift(&mut self, index: usize, weekday: i64, shift: Shift) -> bool {
+        self.hired_empls[index].remove_shift(weekday, shift)
+    }
+}
+
+
+#[cfg(test)]
+use crate::simulator::GameRng;
+#[cfg(test)]
+use menu_file::default_menu;
+
+#[test]
+fn inc_profit_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    //Cash before increment should be set to $1000 by default
+    restaurant_test.receive(1, Category::Sale, Account::Sales, Money::from_dollars(100), "Burger".to_owned());
+    assert_eq!(Money::from_dollars(1100), restaurant_test.get_cash());
+}
+
+#[test]
+fn reduce_profit_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    //Cash before increment should be set to $1000 by default
+    restaurant_test.pay(1, Category::Payroll, Account::Payroll, Money::from_dollars(100), "Wages".to_owned());
+    assert_eq!(Money::from_dollars(900), restaurant_test.get_cash());
+}
+
+#[test]
+fn inc_inv_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    let name = "Patty".to_owned();
+    //Inventory before increment should be set to 100 by default
+    restaurant_test.inc_inv(name.clone(), 25, 1);
+    assert_eq!(125, restaurant_test.get_inv(name));
+}
+
+#[test]
+fn reduce_inv_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    let name = "Patty".to_owned();
+    //Inventory before increment should be set to 100 by default
+    restaurant_test.reduce_inv(name.clone(), 25);
+    assert_eq!(75, restaurant_test.get_inv(name));
+}
+
+#[test]
+fn calc_day_cost_test() {
+    let restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    assert_eq!(Money::from_dollars(174), restaurant_test.calc_empl_day_cost(0))
+}
+
+#[test]
+fn weekend_overtime_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    for weekday in 0..5 {
+        restaurant_test.work_day(weekday);
+    }
+    //The starting staff work 8 hours every day, so the weekend is overtime
+    assert_eq!(24, restaurant_test.calc_overtime(5));
+    assert_eq!(Money::from_dollars(261), restaurant_test.calc_empl_day_cost(5));
+    restaurant_test.reset_week();
+    assert_eq!(Money::from_dollars(174), restaurant_test.calc_empl_day_cost(5));
+}
+
+#[test]
+fn gen_customer_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    let cust_mod = restaurant_test.num_cust_mod();
+    let (shifts, segments) = restaurant_test.generate_customers(0, &mut GameRng::new(1));
+    let wanted: i64 = shifts.iter().map(|shift| shift.wanted).sum();
+    assert!(wanted >= MIN_CUST + cust_mod && wanted <= MAX_CUST + cust_mod);
+    assert_eq!(wanted, segments.iter().map(|segment| segment.wanted).sum::<i64>());
+    for shift in &shifts {
+        assert!(shift.served <= shift.capacity.get_customers());
+    }
+    let served: i64 = shifts.iter().map(|shift| shift.served).sum();
+    assert!(served <= restaurant_test.calc_cust_serv(0));
+    assert_eq!(served, segments.iter().map(|segment| segment.served).sum::<i64>());
+    assert_eq!(served, restaurant_test.customers.iter().map(|customer| customer.get_party()).sum::<i64>());
+}
+
+#[test]
+fn role_capacity_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    //The starting staff work lunch and dinner
+    assert_eq!(16, restaurant_test.calc_cust_serv(0));
+    //Another host doesn't get more food out of the kitchen
+    restaurant_test.hire_emp(Employee::default(employee::HOST, 0, &mut GameRng::new(2)));
+    assert_eq!(16, restaurant_test.calc_cust_serv(0));
+    assert_eq!(Stage::Kitchen, restaurant_test.calc_capacity(0, Slot::Lunch).get_bottleneck().0);
+    //Another cook gets more food out, but the servers can't carry any more of it
+    restaurant_test.hire_emp(Employee::default(COOK, 0, &mut GameRng::new(3)));
+    assert_eq!(16, restaurant_test.calc_cust_serv(0));
+    assert_eq!(Stage::Tables, restaurant_test.calc_capacity(0, Slot::Lunch).get_bottleneck().0);
+    //So another server, now the bottleneck, gets more customers served
+    restaurant_test.hire_emp(Employee::default(employee::SERVER, 0, &mut GameRng::new(4)));
+    assert_eq!(22, restaurant_test.calc_cust_serv(0));
+    assert_eq!(Stage::Cleaning, restaurant_test.calc_capacity(0, Slot::Lunch).get_bottleneck().0);
+}
+
+#[test]
+fn slot_capacity_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    //Nobody works the morning or the close, so nobody is served at breakfast or late at night
+    assert_eq!(0, restaurant_test.calc_capacity(0, Slot::Breakfast).get_customers());
+    assert_eq!(0, restaurant_test.calc_capacity(0, Slot::LateNight).get_customers());
+    //Dinner is only covered for two of its three hours
+    assert_eq!(6, restaurant_test.calc_capacity(0, Slot::Lunch).get_customers());
+    assert_eq!(4, restaurant_test.calc_capacity(0, Slot::Dinner).get_customers());
+    //The cook already works their most hours
+    assert!(!restaurant_test.add_shift(0, 0, Shift::Morning));
+    //Only staff on shift count, a cook alone in the morning still needs a server
+    assert!(restaurant_test.remove_shift(0, 0, Shift::Lunch));
+    assert!(restaurant_test.add_shift(0, 0, Shift::Morning));
+    assert_eq!(0, restaurant_test.calc_capacity(0, Slot::Lunch).get_customers());
+    assert_eq!(0, restaurant_test.calc_capacity(0, Slot::Breakfast).get_customers());
+    assert_eq!(8, restaurant_test.calc_cust_serv(0));
+}
+
+#[test]
+fn customer_orders_test() {
+    let menu = default_menu().items;
+    for seed in 0..20 {
+        let customer = Customer::new(&mut GameRng::new(seed), &menu, &Segment::average(), 1, Slot::Lunch);
+        //Everyone orders a main, sides and drinks are optional
+        assert_eq!("Burger", customer.get_orders()[0]);
+        assert!(customer.get_orders().len() <= 3);
+    }
+    //Everyone in a party orders for themselves
+    let families = &default_menu().segments[1];
+    let customer = Customer::new(&mut GameRng::new(1), &menu, families, 4, Slot::Dinner);
+    let burgers = customer.get_orders().iter().filter(|name| *name == "Burger").count();
+    assert_eq!(4, burgers);
+    assert_eq!("Families", customer.get_segment());
+}
+
+#[test]
+fn recipe_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    //200 potatoes make 100 fries, but the 200 cups are shared with soda
+    assert_eq!(100, restaurant_test.get_servings("Fries".to_owned()));
+    restaurant_test.reduce_inv("Cup".to_owned(), 150);
+    assert_eq!(50, restaurant_test.get_servings("Fries".to_owned()));
+    assert_eq!(50, restaurant_test.get_servings("Soda".to_owned()));
+    restaurant_test.make_item("Fries".to_owned());
+    assert_eq!(198, restaurant_test.get_inv("Potato".to_owned()));
+    assert_eq!(49, restaurant_test.get_servings("Soda".to_owned()));
+}
+
+#[test]
+fn item_quality_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    //Only the buns and patties on hand count, not the quality the next order is bought at
+    restaurant_test.set_ingredient_quality("Patty".to_owned(), 3);
+    restaurant_test.set_ingredient_quality("Bun".to_owned(), 3);
+    assert_eq!(1.0, restaurant_test.get_item_quality("Burger".to_owned()));
+    restaurant_test.inc_inv("Patty".to_owned(), 100, 0);
+    restaurant_test.inc_inv("Bun".to_owned(), 300, 0);
+    //Half the patties and three quarters of the buns are quality 3
+    assert_eq!(2.0, restaurant_test.get_item_quality("Burger".to_owned()));
+    //The oldest, low quality stock is used first
+    assert_eq!(1, restaurant_test.make_item("Burger".to_owned()));
+    restaurant_test.reduce_inv("Patty".to_owned(), 99);
+    restaurant_test.reduce_inv("Bun".to_owned(), 99);
+    assert_eq!(3, restaurant_test.make_item("Burger".to_owned()));
+}
+
+#[test]
+fn spoil_ingredients_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    //Patties keep for 3 days at quality 1
+    let waste = restaurant_test.spoil_ingredients(2);
+    assert_eq!(Money::ZERO, waste.cost);
+    let waste = restaurant_test.spoil_ingredients(3);
+    assert!(waste.spoiled.contains(&("Patty".to_owned(), 100)));
+    assert!(waste.spoiled.contains(&("Cup".to_owned(), 0)));
+    assert_eq!(Money::from_dollars(200), waste.cost);
+    assert_eq!(Money::from_dollars(200), restaurant_test.get_ledger().get_balance(Account::Waste));
+    assert_eq!(0, restaurant_test.get_servings("Burger".to_owned()));
+}
+
+#[test]
+fn sell_item_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    let stock_value = restaurant_test.get_stock_value();
+    assert_eq!(stock_value, restaurant_test.get_ledger().get_balance(Account::Inventory));
+    assert_eq!(1, restaurant_test.sell_item("Burger".to_owned(), Money::from_dollars(5), 1));
+    let ledger = restaurant_test.get_ledger();
+    assert_eq!(Money::from_cents(100540), ledger.get_balance(Account::Cash));
+    assert_eq!(Money::from_dollars(5), ledger.get_balance(Account::Sales));
+    //The sales tax is owed, not earned
+    assert_eq!(Money::from_cents(40), ledger.get_balance(Account::SalesTax));
+    //The stock used comes off the inventory as the cost of the burger
+    let cost = ledger.get_balance(Account::CostOfGoods);
+    assert!(cost > Money::ZERO);
+    assert_eq!(stock_value - cost, restaurant_test.get_stock_value());
+    assert_eq!(stock_value - cost, ledger.get_balance(Account::Inventory));
+    assert_eq!(3, ledger.get_day_entries(1).len());
+}
+
+#[test]
+fn quit_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    let mut rng = GameRng::new(1);
+    assert!(restaurant_test.quit_empls(&mut rng).is_empty());
+    //Underpaid and swamped, everyone quits sooner or later
+    for empl in &mut restaurant_test.hired_empls {
+        empl.set_wage(Money::from_dollars(1));
+        for _ in 0..30 {
+            empl.update_morale(true, 3.0);
+        }
+    }
+    let mut quit = 0;
+    for _ in 0..100 {
+        quit += restaurant_test.quit_empls(&mut rng).len();
+    }
+    assert_eq!(3, quit);
+    assert!(restaurant_test.get_hired_empls().is_empty());
+}
+
+#[test]
+fn training_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    let before = restaurant_test.calc_day_capacity(0);
+    restaurant_test.train_empl(0, COOK, 1);
+    assert_eq!(employee::COURSE_COST, restaurant_test.get_ledger().get_balance(Account::Training));
+    //The cook is away on the course, so nothing gets cooked
+    assert!(restaurant_test.calc_day_capacity(0).get_stage(Stage::Kitchen) < before.get_stage(Stage::Kitchen));
+    assert_eq!(None, restaurant_test.grant_raise(0));
+    //A day on the course is a day off the line, so it doesn't add to the cook's streak
+    assert!(restaurant_test.hired_empls[0].get_day_hours(0) > 0);
+    restaurant_test.update_morale(0, &[]);
+    assert_eq!(0, restaurant_test.hired_empls[0].get_days_straight());
+    let mut progress = Vec::new();
+    for weekday in 0..employee::COURSE_DAYS {
+        progress = restaurant_test.learn(weekday);
+    }
+    assert!(progress[0].trained);
+    let asked = progress[0].asked_wage.unwrap();
+    assert_eq!(Some(asked), restaurant_test.grant_raise(0));
+    assert_eq!(asked, restaurant_test.get_hired_empl(0).get_wage());
+}
+
+#[test]
+fn overhead_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    //Day 1 starts the month, day 7 ends the week and day 28 the month
+    let costs = restaurant_test.pay_overhead(1, 20);
+    assert_eq!(vec![(Account::Utilities, overhead::calc_utilities(20)), (Account::Insurance, overhead::INSURANCE)], costs);
+    assert_eq!(Account::Rent, restaurant_test.pay_overhead(7, 0)[0].0);
+    restaurant_test.sell_item("Burger".to_owned(), Money::from_dollars(5), 7);
+    assert_eq!(vec![(Account::SalesTax, Money::from_cents(40))], restaurant_test.pay_taxes(7));
+    assert_eq!(Money::ZERO, restaurant_test.get_ledger().get_balance(Account::SalesTax));
+    //The month lost money, so there is no income tax
+    assert!(restaurant_test.pay_taxes(28).is_empty());
+    restaurant_test.receive(28, Category::Sale, Account::Sales, Money::from_dollars(5000), "Catering".to_owned());
+    let taxes = restaurant_test.pay_taxes(28);
+    assert_eq!(Account::IncomeTax, taxes[0].0);
+    assert!(taxes[0].1 > Money::ZERO && taxes[0].1 < Money::from_dollars(1000));
+}
+
+#[test]
+fn loan_payments_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    restaurant_test.take_loan(Money::from_dollars(500), 4, 1);
+    restaurant_test.draw_credit(Money::from_dollars(200), 1);
+    assert_eq!(Money::from_dollars(1700), restaurant_test.get_cash());
+    //The credit line is due on Sunday, the loan a week after it was taken out
+    let mut payments = restaurant_test.collect_payments(7);
+    payments.extend(restaurant_test.collect_payments(8));
+    assert_eq!(2, payments.len());
+    let paid: Money = payments.iter().map(|payment| payment.get_total()).sum();
+    assert_eq!(Money::from_dollars(1700) - paid, restaurant_test.get_cash());
+    //The books agree with the bank
+    let ledger = restaurant_test.get_ledger();
+    let owed = ledger.get_balance(Account::Loans) + ledger.get_balance(Account::CreditLine);
+    assert_eq!(restaurant_test.get_debt(), owed);
+    assert!(ledger.get_balance(Account::Interest) > Money::ZERO);
+
+    //A payment that can't be paid adds to what is owed instead
+    restaurant_test.pay(8, Category::Payroll, Account::Payroll, Money::from_dollars(2000), "Wages".to_owned());
+    let debt = restaurant_test.get_debt();
+    let payments = restaurant_test.collect_payments(14);
+    assert!(payments.iter().all(|payment| payment.missed));
+    assert_eq!(1, restaurant_test.get_bank().get_missed());
+    let ledger = restaurant_test.get_ledger();
+    let owed = ledger.get_balance(Account::Loans) + ledger.get_balance(Account::CreditLine);
+    assert!(owed > debt);
+    assert_eq!(restaurant_test.get_debt(), owed);
+}
+
+#[test]
+fn queue_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    for seed in 0..10 {
+        let (slots, _) = restaurant_test.generate_customers(0, &mut GameRng::new(seed));
+        //Nobody works breakfast, so everyone that comes in walks out
+        assert_eq!(0, slots[0].served);
+        for slot in &slots {
+            assert!(slot.served <= slot.capacity.get_customers());
+        }
+        for customer in &restaurant_test.customers {
+            assert!(customer.get_wait() <= customer.get_patience());
+        }
+    }
+}
+
+#[test]
+fn lost_order_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    let mut customer = Customer {
+        cash: Money::from_dollars(50),
+        segment: "Regulars".to_owned(),
+        party: 1,
+        taste: 1.0,
+        slot: Slot::Lunch,
+        patience: 30,
+        wait: 0,
+        orders: vec!["Burger".to_owned()],
+        received: Vec::new(),
+    };
+    restaurant_test.set_item_price("Burger".to_owned(), Money::from_dollars(1000));
+    let mut rng = GameRng::new(1);
+    assert_eq!(Err(LostReason::Price), customer.order(&mut restaurant_test, "Burger".to_owned(), 1, &mut rng));
+    //A price too large to add the tax to is too expensive rather than a crash, even for someone who could pay it
+    restaurant_test.set_item_price("Burger".to_owned(), Money::from_cents(i64::MAX));
+    let mut rich = customer.clone();
+    rich.cash = Money::from_cents(i64::MAX);
+    assert_eq!(Err(LostReason::Price), rich.order(&mut restaurant_test, "Burger".to_owned(), 1, &mut rng));
+    restaurant_test.reduce_inv("Patty".to_owned(), 100);
+    assert_eq!(Err(LostReason::Stockout), customer.order(&mut restaurant_test, "Burger".to_owned(), 1, &mut rng));
+
+    //Burgers are sold out, so both customers leave without buying anything
+    restaurant_test.customers = vec![customer.clone(), customer];
+    let service = restaurant_test.serve_customers(1, &mut rng);
+    assert_eq!(2, service.left_stockout);
+    assert!(service.stockouts.contains(&("Burger".to_owned(), 2)));
+    //Both leave a bad review about the burgers being sold out
+    assert_eq!(2, service.reviews.len());
+    assert!(service.reviews.iter().all(|review| review.stars <= 2));
+    assert_eq!(2, restaurant_test.get_reputation().get_reviews().len());
+}
+
+#[test]
+fn purchase_chance_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    let chance = restaurant_test.calc_purchase_chance("Burger".to_owned(), &Segment::average(), 1.0);
+    //Most customers buy at the reference price
+    assert!(chance > 0.8);
+    restaurant_test.set_item_price("Burger".to_owned(), Money::from_cents(750));
+    let high_price = restaurant_test.calc_purchase_chance("Burger".to_owned(), &Segment::average(), 1.0);
+    assert!(high_price < chance);
+    //Better burgers are worth more
+    restaurant_test.set_ingredient_quality("Bun".to_owned(), 3);
+    restaurant_test.set_ingredient_quality("Patty".to_owned(), 3);
+    restaurant_test.reduce_inv("Bun".to_owned(), 100);
+    restaurant_test.reduce_inv("Patty".to_owned(), 100);
+    restaurant_test.inc_inv("Bun".to_owned(), 10, 0);
+    restaurant_test.inc_inv("Patty".to_owned(), 10, 0);
+    assert!(restaurant_test.calc_purchase_chance("Burger".to_owned(), &Segment::average(), 1.0) > high_price);
+}
+
+#[test]
+fn segment_test() {
+    let mut restaurant_test = Restaurant::new("Test".to_owned(), default_menu(), &mut GameRng::new(1));
+    let students = restaurant_test.get_segment("Students").unwrap().clone();
+    let foodies = restaurant_test.get_segment("Foodies").unwrap().clone();
+    //Students are put off by a high price long before foodies are
+    restaurant_test.set_item_price("Burger".to_owned(), Money::from_dollars(9));
+    assert!(
+        restaurant_test.calc_purchase_chance("Burger".to_owned(), &students, 1.0)
+            < restaurant_test.calc_purchase_chance("Burger".to_owned(), &foodies, 1.0)
+    );
+
+    //Office workers fill the week and families the weekend
+    let mut rng = GameRng::new(1);
+    let mut monday = (0, 0);
+    let mut saturday = (0, 0);
+    for _ in 0..10 {
+        let (_, segments) = restaurant_test.generate_customers(0, &mut rng);
+        monday.0 += segments[1].wanted;
+        monday.1 += segments[2].wanted;
+        let (_, segments) = restaurant_test.generate_customers(5, &mut rng);
+        saturday.0 += segments[1].wanted;
+        saturday.1 += segments[2].wanted;
+    }
+    assert!(monday.1 > monday.0);
+    assert!(saturday.0 > saturday.1);
+}
+
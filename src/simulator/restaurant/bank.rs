@@ -0,0 +1,343 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following is the bank the restaurant borrows from.
+// The bank offers loans and a revolving credit line.
+// A loan is a fixed amount paid back in equal weekly payments over a number of weeks,
+// at an interest rate that is set when the loan is taken out.
+// The credit line can be drawn on and paid back at any time up to its limit.
+// Every Sunday interest is charged on what is owed on it, along with a minimum payment.
+// Interest rates are weekly and depend on how profitable the restaurant has been
+// over the last RATE_DAYS days: the better the profit margin the lower the rate.
+// The credit line's rate changes every week, a loan's rate never does.
+// How much can be borrowed grows with the restaurant's recent sales.
+// A payment the restaurant can't afford is missed: the interest and a late fee
+// are added to what is owed, and after enough missed payments in a row the loan defaults,
+// see GameRules.
+
+use super::schedule;
+use crate::simulator::Money;
+use serde::{Deserialize, Serialize};
+
+// Days of history the interest rate and borrowing limit are based on
+pub const RATE_DAYS: i64 = 28;
+// Shortest and longest loans, in weeks
+pub const MIN_WEEKS: i64 = 4;
+pub const MAX_WEEKS: i64 = 52;
+// Weekly interest rate at a profit margin of zero, and how far the margin moves it
+const BASE_RATE: f64 = 0.015;
+const MARGIN_EFFECT: f64 = 0.02;
+const MIN_RATE: f64 = 0.005;
+const MAX_RATE: f64 = 0.03;
+// The credit line costs this much more a week than a loan
+const CREDIT_PREMIUM: f64 = 0.005;
+// Loans anyone can get, more can be borrowed on top of recent sales
+const BASE_LOAN_LIMIT: Money = Money::from_cents(200_000);
+const CREDIT_LIMIT: Money = Money::from_cents(100_000);
+// Share of the credit line balance that must be paid every week, and the least that must be paid
+const MIN_PAYMENT_SHARE: f64 = 0.1;
+const MIN_PAYMENT: Money = Money::from_cents(2_500);
+// Added to what is owed for every missed payment
+pub const LATE_FEE: Money = Money::from_cents(2_500);
+
+//Weekly interest rate for a loan, given the net income and sales of the last RATE_DAYS days
+//A restaurant with no sales yet gets the base rate
+pub fn calc_rate(net_income: Money, sales: Money) -> f64 {
+    let margin = if sales > Money::ZERO {
+        net_income.to_f64() / sales.to_f64()
+    } else if net_income.is_negative() {
+        -1.0
+    } else {
+        0.0
+    };
+    (BASE_RATE - margin * MARGIN_EFFECT).clamp(MIN_RATE, MAX_RATE)
+}
+
+//Weekly interest rate for the credit line at the given loan rate
+pub fn calc_credit_rate(loan_rate: f64) -> f64 {
+    loan_rate + CREDIT_PREMIUM
+}
+
+//Equal weekly payment that pays off the amount with interest over the given weeks
+//Rounded up to the cent so the last payment never leaves a cent owing
+pub fn calc_payment(amount: Money, rate: f64, weeks: i64) -> Money {
+    let weeks = weeks.max(1);
+    let payment = if rate <= 0.0 {
+        amount.to_f64() / weeks as f64
+    } else {
+        amount.to_f64() * rate / (1.0 - (1.0 + rate).powi(-weeks as i32))
+    };
+    Money::from_cents((payment * 100.0).ceil() as i64)
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Loan {
+    id: i64,
+    //Amount borrowed
+    amount: Money,
+    //Amount still owed
+    balance: Money,
+    //Weekly interest rate
+    rate: f64,
+    //Payment due every week
+    payment: Money,
+    //Day the next payment is due
+    next_due: i64,
+    //Payments missed in a row
+    missed: i64,
+}
+
+impl Loan {
+    //Getters
+    pub fn get_id(&self) -> i64 {
+        self.id
+    }
+
+    pub fn get_amount(&self) -> Money {
+        self.amount
+    }
+
+    pub fn get_balance(&self) -> Money {
+        self.balance
+    }
+
+    pub fn get_rate(&self) -> f64 {
+        self.rate
+    }
+
+    pub fn get_payment(&self) -> Money {
+        self.payment
+    }
+
+    pub fn get_next_due(&self) -> i64 {
+        self.next_due
+    }
+
+    pub fn get_missed(&self) -> i64 {
+        self.missed
+    }
+}
+
+// What is owed, a loan by its id or the credit line
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Debt {
+    Loan(i64),
+    CreditLine,
+}
+
+impl Debt {
+    pub fn get_name(self) -> String {
+        match self {
+            Debt::Loan(id) => format!("Loan {}", id),
+            Debt::CreditLine => "Credit line".to_owned(),
+        }
+    }
+}
+
+// A payment that came due
+#[derive(Clone, Debug)]
+pub struct Payment {
+    pub debt: Debt,
+    //Interest and principal paid, or that would have been paid if it was missed
+    pub interest: Money,
+    pub principal: Money,
+    //Whether the restaurant couldn't afford it
+    pub missed: bool,
+}
+
+impl Payment {
+    pub fn get_total(&self) -> Money {
+        self.interest + self.principal
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Bank {
+    loans: Vec<Loan>,
+    //Amount owed on the credit line
+    credit: Money,
+    //Credit line payments missed in a row
+    credit_missed: i64,
+    //Id the next loan gets
+    next_id: i64,
+}
+
+impl Bank {
+    pub fn new() -> Bank {
+        Bank {
+            loans: Vec::new(),
+            credit: Money::ZERO,
+            credit_missed: 0,
+            next_id: 1,
+        }
+    }
+
+    //Getters
+    pub fn get_loans(&self) -> &[Loan] {
+        &self.loans
+    }
+
+    pub fn get_credit(&self) -> Money {
+        self.credit
+    }
+
+    //Everything owed to the bank
+    pub fn get_debt(&self) -> Money {
+        self.loans.iter().map(|loan| loan.balance).sum::<Money>() + self.credit
+    }
+
+    //Most payments missed in a row on any one debt
+    pub fn get_missed(&self) -> i64 {
+        self.loans.iter().map(|loan| loan.missed).fold(self.credit_missed, i64::max)
+    }
+
+    //Most that can be borrowed as a new loan, given the sales of the last RATE_DAYS days
+    pub fn calc_loan_limit(&self, sales: Money) -> Money {
+        let owed: Money = self.loans.iter().map(|loan| loan.balance).sum();
+        (BASE_LOAN_LIMIT + sales - owed).max(Money::ZERO)
+    }
+
+    //Most that can still be drawn on the credit line
+    pub fn calc_credit_left(&self) -> Money {
+        (CREDIT_LIMIT - self.credit).max(Money::ZERO)
+    }
+
+    pub fn get_credit_limit(&self) -> Money {
+        CREDIT_LIMIT
+    }
+
+    //Takes out a loan on the given day, the first payment is due a week later
+    //Returns the id of the new loan
+    pub fn take_loan(&mut self, amount: Money, rate: f64, weeks: i64, day: i64) -> i64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.loans.push(Loan {
+            id,
+            amount,
+            balance: amount,
+            rate,
+            payment: calc_payment(amount, rate, weeks),
+            next_due: day + 7,
+            missed: 0,
+        });
+        id
+    }
+
+    pub fn draw(&mut self, amount: Money) {
+        self.credit += amount;
+    }
+
+    //Pays back up to the given amount of the credit line
+    //Returns the amount paid
+    pub fn repay(&mut self, amount: Money) -> Money {
+        let paid = amount.min(self.credit);
+        self.credit -= paid;
+        if self.credit.is_zero() {
+            self.credit_missed = 0;
+        }
+        paid
+    }
+
+    //Collects every payment due on the given day out of the given cash
+    //Loans are due every week from the day they were taken out, the credit line every Sunday
+    //A payment that costs more than the cash left is missed,
+    //and the interest and LATE_FEE are added to what is owed instead
+    pub fn collect(&mut self, day: i64, mut cash: Money, credit_rate: f64) -> Vec<Payment> {
+        let mut payments: Vec<Payment> = Vec::new();
+        for loan in &mut self.loans {
+            if loan.next_due > day {
+                continue;
+            }
+            loan.next_due += 7;
+            let interest = loan.balance.mul_f64(loan.rate);
+            let principal = (loan.payment - interest).min(loan.balance).max(Money::ZERO);
+            let missed = interest + principal > cash;
+            if missed {
+                loan.missed += 1;
+                loan.balance += interest + LATE_FEE;
+            } else {
+                loan.missed = 0;
+                loan.balance -= principal;
+                cash -= interest + principal;
+            }
+            payments.push(Payment { debt: Debt::Loan(loan.id), interest, principal, missed });
+        }
+        self.loans.retain(|loan| loan.balance > Money::ZERO);
+
+        if schedule::get_weekday(day) == 6 && self.credit > Money::ZERO {
+            let interest = self.credit.mul_f64(credit_rate);
+            let principal = self.credit.mul_f64(MIN_PAYMENT_SHARE).max(MIN_PAYMENT).min(self.credit);
+            let missed = interest + principal > cash;
+            if missed {
+                self.credit_missed += 1;
+                self.credit += interest + LATE_FEE;
+            } else {
+                self.credit_missed = 0;
+                self.credit -= principal;
+            }
+            payments.push(Payment { debt: Debt::CreditLine, interest, principal, missed });
+        }
+        payments
+    }
+}
+
+#[test]
+fn rate_test() {
+    assert_eq!(BASE_RATE, calc_rate(Money::ZERO, Money::ZERO));
+    assert_eq!(MAX_RATE, calc_rate(Money::from_dollars(-100), Money::ZERO));
+    //Profitable restaurants borrow for less
+    let profitable = calc_rate(Money::from_dollars(250), Money::from_dollars(1000));
+    let losing = calc_rate(Money::from_dollars(-250), Money::from_dollars(1000));
+    assert!(profitable < BASE_RATE && BASE_RATE < losing);
+    assert!(calc_credit_rate(profitable) > profitable);
+}
+
+#[test]
+fn loan_test() {
+    let mut bank = Bank::new();
+    let id = bank.take_loan(Money::from_dollars(1000), 0.01, 4, 1);
+    assert_eq!(Money::from_dollars(1000), bank.get_debt());
+    //Nothing is due until a week later
+    assert!(bank.collect(7, Money::from_dollars(1000), 0.0).is_empty());
+    let mut paid = Money::ZERO;
+    for week in 1..=4 {
+        let payments = bank.collect(1 + 7 * week, Money::from_dollars(1000), 0.0);
+        assert_eq!(Debt::Loan(id), payments[0].debt);
+        assert!(!payments[0].missed);
+        paid += payments[0].get_total();
+    }
+    //Four payments pay off the loan with interest
+    assert!(bank.get_loans().is_empty());
+    assert!(paid > Money::from_dollars(1000) && paid < Money::from_dollars(1030));
+    assert_eq!(Money::from_cents(25), calc_payment(Money::from_dollars(1), 0.0, 4));
+}
+
+#[test]
+fn missed_payment_test() {
+    let mut bank = Bank::new();
+    bank.take_loan(Money::from_dollars(1000), 0.01, 4, 1);
+    let payments = bank.collect(8, Money::from_dollars(10), 0.0);
+    assert!(payments[0].missed);
+    assert_eq!(1, bank.get_missed());
+    assert_eq!(Money::from_dollars(1010) + LATE_FEE, bank.get_debt());
+    bank.collect(15, Money::from_dollars(1000), 0.0);
+    assert_eq!(0, bank.get_missed());
+}
+
+#[test]
+fn credit_line_test() {
+    let mut bank = Bank::new();
+    bank.draw(Money::from_dollars(500));
+    assert_eq!(CREDIT_LIMIT - Money::from_dollars(500), bank.calc_credit_left());
+    assert!(bank.collect(6, Money::from_dollars(1000), 0.02).is_empty());
+    //Day 7 is a Sunday
+    let payments = bank.collect(7, Money::from_dollars(1000), 0.02);
+    assert_eq!(Money::from_dollars(10), payments[0].interest);
+    assert_eq!(Money::from_dollars(50), payments[0].principal);
+    assert_eq!(Money::from_dollars(450), bank.get_credit());
+    assert_eq!(Money::from_dollars(100), bank.repay(Money::from_dollars(100)));
+    assert_eq!(Money::from_dollars(350), bank.repay(Money::from_dollars(1000)));
+    assert_eq!(Money::ZERO, bank.get_debt());
+}
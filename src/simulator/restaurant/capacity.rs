@@ -0,0 +1,176 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following works out how many customers the staff working a stretch of the day can handle.
+// Every customer goes through the same stages: they are seated by a host,
+// their table is looked after by a server, their food is made by a cook,
+// and afterwards the dishes and table are cleaned by washers and bussers.
+// Each stage can only handle so many customers, based on the total rating
+// of the employees working it, as low morale has them working, and the slowest stage, the bottleneck,
+// decides how many customers are served.
+// Customers can seat themselves and a little cleaning gets done without help,
+// so seating and cleaning still have some capacity with nobody on them.
+// The numbers below are for a full day of DAY_HOURS, a shorter stretch handles fewer,
+// and an employee that is only there for part of a stretch helps for the hours they are there.
+
+use super::employee::{Employee, BUSSER, COOK, HOST, SERVER, WASHER};
+
+// Customers one point of employee rating can handle in a day
+const PER_RATING: i64 = 3;
+// Customers that get seated in a day with no host working
+const BASE_SEATING: i64 = 15;
+// Customers that can be cleaned up after in a day with no washers or bussers working
+const BASE_CLEANING: i64 = 5;
+// Hours in the full day the numbers above are for
+const DAY_HOURS: i64 = 8;
+
+// The stages every customer goes through
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Stage {
+    //Cooks making the food
+    Kitchen,
+    //Servers looking after tables
+    Tables,
+    //Hosts seating customers
+    Seating,
+    //Washers and bussers keeping dishes and tables clean
+    Cleaning,
+}
+
+impl Stage {
+    pub fn get_name(self) -> String {
+        match self {
+            Stage::Kitchen => "Kitchen".to_owned(),
+            Stage::Tables => "Tables".to_owned(),
+            Stage::Seating => "Seating".to_owned(),
+            Stage::Cleaning => "Cleaning".to_owned(),
+        }
+    }
+
+    //The stage employees in the given position work
+    pub fn of_posit(posit: i64) -> Stage {
+        match posit {
+            COOK => Stage::Kitchen,
+            SERVER => Stage::Tables,
+            HOST => Stage::Seating,
+            _ => Stage::Cleaning,
+        }
+    }
+
+    //Who works the stage
+    pub fn get_description(self) -> String {
+        match self {
+            Stage::Kitchen => "cooks".to_owned(),
+            Stage::Tables => "servers".to_owned(),
+            Stage::Seating => "hosts".to_owned(),
+            Stage::Cleaning => "washers and bussers".to_owned(),
+        }
+    }
+}
+
+// Customers each stage can handle
+#[derive(Clone)]
+pub struct Capacity {
+    pub stages: Vec<(Stage, i64)>,
+}
+
+impl Capacity {
+    //Works out the capacity of every stage over a stretch of the given hours
+    //from the employees working it and the hours each of them is there
+    pub fn new(staff: &[(&Employee, i64)], hours: i64) -> Capacity {
+        let customers = |base: i64, posits: &[i64]| -> i64 {
+            let rating_hours: i64 = staff
+                .iter()
+                .filter(|(empl, _)| posits.contains(&empl.get_posit()))
+                .map(|(empl, worked)| empl.get_effective_rating() * worked)
+                .sum();
+            ((base * hours + rating_hours * PER_RATING) as f64 / DAY_HOURS as f64).round() as i64
+        };
+
+        Capacity {
+            stages: vec![
+                (Stage::Kitchen, customers(0, &[COOK])),
+                (Stage::Tables, customers(0, &[SERVER])),
+                (Stage::Seating, customers(BASE_SEATING, &[HOST])),
+                (Stage::Cleaning, customers(BASE_CLEANING, &[WASHER, BUSSER])),
+            ],
+        }
+    }
+
+    //Adds up the capacity of several stretches of time, stage by stage
+    pub fn total(capacities: &[Capacity]) -> Capacity {
+        let mut total = Capacity::new(&[], 0);
+        for capacity in capacities {
+            for (stage, customers) in &mut total.stages {
+                *customers += capacity.get_stage(*stage);
+            }
+        }
+        total
+    }
+
+    //The stage that can handle the fewest customers
+    //When stages tie the one earliest in the list is named
+    pub fn get_bottleneck(&self) -> (Stage, i64) {
+        let mut bottleneck = self.stages[0];
+        for stage in &self.stages {
+            if stage.1 < bottleneck.1 {
+                bottleneck = *stage;
+            }
+        }
+        bottleneck
+    }
+
+    //Customers that can be served, the capacity of the bottleneck
+    pub fn get_customers(&self) -> i64 {
+        self.get_bottleneck().1
+    }
+
+    pub fn get_stage(&self, stage: Stage) -> i64 {
+        self.stages
+            .iter()
+            .find(|(other, _)| *other == stage)
+            .map_or(0, |(_, capacity)| *capacity)
+    }
+}
+
+#[cfg(test)]
+use crate::simulator::GameRng;
+
+#[cfg(test)]
+fn test_empl(posit: i64, rating: i64) -> Employee {
+    let mut empl = Employee::default(posit, 1, &mut GameRng::new(1));
+    empl.set_rating(rating);
+    empl
+}
+
+#[test]
+fn capacity_test() {
+    let empls = [test_empl(COOK, 5), test_empl(SERVER, 8), test_empl(WASHER, 2), test_empl(BUSSER, 3)];
+    let staff: Vec<(&Employee, i64)> = empls.iter().map(|empl| (empl, DAY_HOURS)).collect();
+    let capacity = Capacity::new(&staff, DAY_HOURS);
+    assert_eq!(15, capacity.get_stage(Stage::Kitchen));
+    assert_eq!(24, capacity.get_stage(Stage::Tables));
+    assert_eq!(BASE_SEATING, capacity.get_stage(Stage::Seating));
+    assert_eq!(BASE_CLEANING + 15, capacity.get_stage(Stage::Cleaning));
+    assert_eq!((Stage::Kitchen, 15), capacity.get_bottleneck());
+    //Half the day handles half the customers
+    let staff: Vec<(&Employee, i64)> = empls.iter().map(|empl| (empl, DAY_HOURS / 2)).collect();
+    let half = Capacity::new(&staff, DAY_HOURS / 2);
+    assert_eq!(12, half.get_stage(Stage::Tables));
+    assert_eq!(24, Capacity::total(&[half.clone(), half]).get_stage(Stage::Tables));
+    //So does a server that is only there for half of it
+    let staff = vec![(&empls[1], DAY_HOURS / 2)];
+    assert_eq!(12, Capacity::new(&staff, DAY_HOURS).get_stage(Stage::Tables));
+}
+
+#[test]
+fn no_cooks_test() {
+    //Five hosts can't make any food
+    let empls: Vec<Employee> = (0..5).map(|_| test_empl(HOST, 10)).collect();
+    let staff: Vec<(&Employee, i64)> = empls.iter().map(|empl| (empl, DAY_HOURS)).collect();
+    let capacity = Capacity::new(&staff, DAY_HOURS);
+    assert_eq!(0, capacity.get_customers());
+    assert_eq!(Stage::Kitchen, capacity.get_bottleneck().0);
+}
@@ -0,0 +1,64 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following decides whether a customer is willing to pay the price of a menu item.
+// Every menu item has a reference price, what customers expect to pay for it at quality 1.
+// A customer's willingness to pay is the price where they are as likely to buy as not,
+// and is worth more the better the item is and the more the customer likes that kind of food.
+// Below that price customers almost always buy, above it they almost never do,
+// so raising a price earns more on every sale but makes fewer sales.
+
+// How much a customer cares about quality and price depends on their segment, see segment.
+
+// Willingness to pay of an average customer at quality 1, as a multiple of the reference price
+const BASE_WTP: f64 = 1.5;
+// How much more an average customer will pay for every level of quality above 1, as a fraction
+pub const QUALITY_PREMIUM: f64 = 0.25;
+// How fast an average customer's chance of buying falls as the price goes past their willingness to pay
+pub const PRICE_SENSITIVITY: f64 = 5.0;
+
+//The price a customer with the given taste is as likely to pay as not
+//for an item with the given reference price and quality
+//An average customer has a taste of 1 and pays QUALITY_PREMIUM more for every level of quality
+pub fn willingness_to_pay(reference_price: f64, quality: f64, taste: f64, quality_premium: f64) -> f64 {
+    let quality = quality.max(1.0);
+    reference_price * BASE_WTP * (1.0 + quality_premium * (quality - 1.0)) * taste
+}
+
+//Chance from 0 to 1 that a customer buys an item at the given price
+//when they are willing to pay the given amount
+//The more sensitive to price the customer is, the faster the chance falls past what they will pay
+pub fn purchase_chance(price: f64, wtp: f64, sensitivity: f64) -> f64 {
+    if wtp <= 0.0 {
+        return 0.0;
+    }
+    1.0 / (1.0 + (sensitivity * (price / wtp - 1.0)).exp())
+}
+
+#[test]
+fn willingness_to_pay_test() {
+    assert_eq!(6.0, willingness_to_pay(4.0, 1.0, 1.0, QUALITY_PREMIUM));
+    assert_eq!(9.0, willingness_to_pay(4.0, 3.0, 1.0, QUALITY_PREMIUM));
+    assert_eq!(3.0, willingness_to_pay(4.0, 1.0, 0.5, QUALITY_PREMIUM));
+    //Customers that don't care about quality pay the same for anything
+    assert_eq!(6.0, willingness_to_pay(4.0, 3.0, 1.0, 0.0));
+}
+
+#[test]
+fn purchase_chance_test() {
+    assert_eq!(0.5, purchase_chance(6.0, 6.0, PRICE_SENSITIVITY));
+    //The chance only falls as the price rises
+    let mut last = 1.0;
+    for cents in 1..2000 {
+        let chance = purchase_chance(cents as f64 / 100.0, 6.0, PRICE_SENSITIVITY);
+        assert!(chance <= last);
+        last = chance;
+    }
+    assert!(purchase_chance(3.0, 6.0, PRICE_SENSITIVITY) > 0.9);
+    assert!(purchase_chance(12.0, 6.0, PRICE_SENSITIVITY) < 0.01);
+    assert_eq!(0.0, purchase_chance(1.0, 0.0, PRICE_SENSITIVITY));
+    //Customers that are more sensitive to price are put off sooner
+    assert!(purchase_chance(7.0, 6.0, 10.0) < purchase_chance(7.0, 6.0, PRICE_SENSITIVITY));
+}
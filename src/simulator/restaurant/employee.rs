@@ -0,0 +1,559 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following code is used to represent an employee at the restaurant.
+// An employee has a name, wage, an id, a position and a rating
+// Their wage is based off their rating with a higher rating giving them a higher wage.
+// The wage will never be below the minimum
+// An employee also has a weekly schedule of the shifts they work, see schedule,
+// a morale that changes every day and experience that raises their rating.
+// The employee doesn't have much function just manages its own data
+
+use super::schedule::{self, Shift, OVERTIME_HOURS, OVERTIME_RATE, SHIFT_HOURS};
+use crate::simulator::Money;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+mod names;
+use names::{Generator, Name};
+
+//Minimum hourly wage, in cents
+const MINWAGE: Money = Money::from_cents(725);
+//Most hours a week the starting employees work
+const STARTING_MAX_HOURS: i64 = 56;
+//Morale every employee starts at and drifts back toward, and the share of the way it drifts every day
+const BASE_MORALE: f64 = 70.0;
+const MORALE_DRIFT: f64 = 0.1;
+//Morale gained a day for every tenth more than a fair wage they are paid, and the most it changes
+const WAGE_EFFECT: f64 = 1.0;
+const MAX_WAGE_EFFECT: f64 = 4.0;
+//Morale lost a day for every time over their stage's capacity the customers were, and the most lost
+const LOAD_EFFECT: f64 = 2.0;
+const MAX_LOAD_EFFECT: f64 = 4.0;
+//Morale gained on a day off
+const DAY_OFF_EFFECT: f64 = 3.0;
+//Days in a row an employee works before it wears on them,
+//and the morale lost for every day past it, up to the most lost
+const MAX_DAYS_STRAIGHT: i64 = 6;
+const STREAK_EFFECT: f64 = 1.0;
+const MAX_STREAK_EFFECT: f64 = 3.0;
+//Below this morale employees work below their rating
+const CONTENT_MORALE: f64 = 50.0;
+//Below this morale employees may quit, and the chance they quit a day at a morale of zero
+pub const QUIT_MORALE: f64 = 25.0;
+const MAX_QUIT_CHANCE: f64 = 0.3;
+//Shifts it takes to go up a point of rating, for every point of rating the employee has
+const SHIFTS_PER_POINT: i64 = 10;
+//Highest rating an employee can have
+pub const MAX_RATING: i64 = 10;
+//What a training course costs, the days it takes and the points of rating it gives
+pub const COURSE_COST: Money = Money::from_cents(20_000);
+pub const COURSE_DAYS: i64 = 3;
+pub const COURSE_GAIN: i64 = 2;
+
+//Positions represented as integers
+pub const COOK: i64 = 0;
+pub const SERVER: i64 = 1;
+pub const WASHER: i64 = 2;
+pub const BUSSER: i64 = 3;
+pub const HOST: i64 = 4;
+//Every position, in order
+pub const POSITIONS: [i64; 5] = [COOK, SERVER, WASHER, BUSSER, HOST];
+
+//Converts a position to a string representation
+pub fn get_posit_name(posit: i64) -> String {
+    match posit {
+        COOK => "Cook".to_owned(),
+        SERVER => "Server".to_owned(),
+        WASHER => "Washer".to_owned(),
+        BUSSER => "Busser".to_owned(),
+        _ => "Host".to_owned(),
+    }
+}
+
+//Reads a position from its name, e.g. cook or Server
+pub fn parse_posit(text: &str) -> Option<i64> {
+    POSITIONS
+        .iter()
+        .find(|posit| get_posit_name(**posit).eq_ignore_ascii_case(text))
+        .copied()
+}
+
+// A training course an employee is on
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Course {
+    //Position the course trains for
+    pub posit: i64,
+    //Days left until it is done
+    pub days_left: i64,
+}
+
+// How an employee got better at the end of a day
+#[derive(Clone, PartialEq, Debug)]
+pub struct Progress {
+    pub name: String,
+    //Position and rating after
+    pub posit: i64,
+    pub rating: i64,
+    //Whether it came from finishing a training course instead of experience
+    pub trained: bool,
+    //Wage the employee asked for, if they are paid less than their new rating is worth
+    pub asked_wage: Option<Money>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Employee {
+    name: String,
+    id: i64,
+    //Pay for an hour of work
+    wage: Money,
+    //Rating of employee ranges from 1-10
+    rating: i64,
+    //Name of position
+    posit: i64,
+    //Most hours a week the employee can be scheduled for
+    max_hours: i64,
+    //Day of the week and shift of every shift the employee works
+    shifts: Vec<(i64, Shift)>,
+    //Hours worked so far this week, for overtime
+    week_hours: i64,
+    //How happy the employee is, from 0 to 100
+    morale: f64,
+    //Days worked in a row since their last day off
+    days_straight: i64,
+    //Shifts worked toward the next point of rating
+    experience: i64,
+    //Training course the employee is on, if any
+    course: Option<Course>,
+    //Wage the employee asked for after their rating went up, until they get it
+    asked_wage: Option<Money>,
+}
+
+impl Employee {
+    //Default employee constructor
+    //Used to fill the hired employee list in a Restaurant upon creation of said Restaurant.
+    pub fn default<R: Rng>(posit: i64, id: i64, rng: &mut R) -> Employee {
+        let mut generator = Generator::with_naming(Name::Plain, rng);
+        let name = generator.next().unwrap();
+        let rating = 5;
+        let wage = MINWAGE;
+        let max_hours = STARTING_MAX_HOURS;
+
+        Employee {
+            name,
+            id,
+            wage,
+            rating,
+            posit,
+            max_hours,
+            shifts: schedule::fill_schedule(max_hours),
+            week_hours: 0,
+            morale: BASE_MORALE,
+            days_straight: 0,
+            experience: 0,
+            course: None,
+            asked_wage: None,
+        }
+    }
+
+    //Generates a random employee
+    //Used to fill potential employees list for a Restaurant
+    pub fn rand_empl<R: Rng>(rng: &mut R) -> Employee {
+        let name = Generator::with_naming(Name::Plain, &mut *rng).next().unwrap();
+        let id = rng.gen_range(1, 99999);
+        let rating = rng.gen_range(1, 11);
+        let wage = Employee::calc_fair_wage(rating);
+        let posit = rng.gen_range(COOK, HOST + 1);
+        //Anywhere from four to fourteen shifts a week
+        let max_hours = rng.gen_range(4, 15) * SHIFT_HOURS;
+
+        Employee {
+            name,
+            id,
+            wage,
+            rating,
+            posit,
+            max_hours,
+            shifts: schedule::fill_schedule(max_hours),
+            week_hours: 0,
+            morale: BASE_MORALE,
+            days_straight: 0,
+            experience: 0,
+            course: None,
+            asked_wage: None,
+        }
+    }
+
+    //Wage an employee with the given rating expects, a dollar more than minimum wage for every point over 5
+    pub fn calc_fair_wage(rating: i64) -> Money {
+        MINWAGE + Money::from_dollars((rating - 5).max(0))
+    }
+
+    //Getters
+    pub fn get_name(&self) -> String {
+        self.name.to_owned()
+    }
+
+    pub fn get_wage(&self) -> Money {
+        self.wage
+    }
+
+    pub fn get_posit(&self) -> i64 {
+        self.posit
+    }
+
+    //Converts an employee position to a string representation
+    pub fn get_posit_string(&self) -> String {
+        get_posit_name(self.posit)
+    }
+
+    pub fn get_id(&self) -> i64 {
+        self.id
+    }
+
+    pub fn get_rating(&self) -> i64 {
+        self.rating
+    }
+
+    //Rating the employee works at, lower than their rating when their morale is low
+    //At a morale of zero they work at half their rating
+    pub fn get_effective_rating(&self) -> i64 {
+        if self.morale >= CONTENT_MORALE {
+            return self.rating;
+        }
+        let share = 0.5 + 0.5 * self.morale / CONTENT_MORALE;
+        ((self.rating as f64 * share).round() as i64).max(1)
+    }
+
+    pub fn get_morale(&self) -> f64 {
+        self.morale
+    }
+
+    pub fn get_days_straight(&self) -> i64 {
+        self.days_straight
+    }
+
+    pub fn get_experience(&self) -> i64 {
+        self.experience
+    }
+
+    //Shifts it takes to go up from the current rating
+    pub fn get_shifts_to_improve(&self) -> i64 {
+        SHIFTS_PER_POINT * self.rating
+    }
+
+    pub fn get_course(&self) -> Option<Course> {
+        self.course
+    }
+
+    pub fn is_training(&self) -> bool {
+        self.course.is_some()
+    }
+
+    pub fn get_asked_wage(&self) -> Option<Money> {
+        self.asked_wage
+    }
+
+    //Chance the employee quits at the start of a day, nothing unless their morale is below QUIT_MORALE
+    pub fn calc_quit_chance(&self) -> f64 {
+        if self.morale >= QUIT_MORALE {
+            return 0.0;
+        }
+        (QUIT_MORALE - self.morale) / QUIT_MORALE * MAX_QUIT_CHANCE
+    }
+
+    pub fn get_max_hours(&self) -> i64 {
+        self.max_hours
+    }
+
+    pub fn get_shifts(&self) -> &[(i64, Shift)] {
+        &self.shifts
+    }
+
+    //Checks if the employee works the given shift on the given day of the week
+    pub fn works(&self, weekday: i64, shift: Shift) -> bool {
+        self.shifts.contains(&(weekday, shift))
+    }
+
+    //Hours the employee is scheduled for in a week
+    pub fn get_sched_hours(&self) -> i64 {
+        self.shifts.len() as i64 * SHIFT_HOURS
+    }
+
+    //Hours the employee is scheduled for on the given day of the week
+    pub fn get_day_hours(&self, weekday: i64) -> i64 {
+        self.shifts.iter().filter(|(day, _)| *day == weekday).count() as i64 * SHIFT_HOURS
+    }
+
+    //Hours worked on the given day of the week that are paid as overtime
+    pub fn get_overtime(&self, weekday: i64) -> i64 {
+        let hours = self.get_day_hours(weekday);
+        let regular = (OVERTIME_HOURS - self.week_hours).clamp(0, hours);
+        hours - regular
+    }
+
+    //Pay for working the given day of the week, with overtime
+    //Rounded to the nearest cent
+    pub fn calc_day_pay(&self, weekday: i64) -> Money {
+        let overtime = self.get_overtime(weekday);
+        let regular = self.get_day_hours(weekday) - overtime;
+        self.wage.mul_f64(regular as f64 + overtime as f64 * OVERTIME_RATE)
+    }
+
+    //Setters
+    pub fn set_id(&mut self, new_id: i64) {
+        self.id = new_id;
+    }
+
+    //Paying at least what the employee asked for settles the request
+    pub fn set_wage(&mut self, new_wage: Money) {
+        self.wage = new_wage;
+        if self.asked_wage.is_some_and(|asked| new_wage >= asked) {
+            self.asked_wage = None;
+        }
+    }
+
+    //Rating is kept within the 1-10 range
+    pub fn set_rating(&mut self, new_rating: i64) {
+        self.rating = new_rating.clamp(1, 10);
+    }
+
+    //Adds a shift to the schedule
+    //Returns false if the employee already works it or it would go over their hours
+    pub fn add_shift(&mut self, weekday: i64, shift: Shift) -> bool {
+        if self.works(weekday, shift) || self.get_sched_hours() + SHIFT_HOURS > self.max_hours {
+            return false;
+        }
+        self.shifts.push((weekday, shift));
+        true
+    }
+
+    //Takes a shift off the schedule
+    //Returns false if the employee didn't work it
+    pub fn remove_shift(&mut self, weekday: i64, shift: Shift) -> bool {
+        let len = self.shifts.len();
+        self.shifts.retain(|worked| *worked != (weekday, shift));
+        self.shifts.len() < len
+    }
+
+    //Adds the hours of the given day of the week to the hours worked this week
+    pub fn work_day(&mut self, weekday: i64) {
+        self.week_hours += self.get_day_hours(weekday);
+    }
+
+    //Starts a new week of hours
+    pub fn reset_week(&mut self) {
+        self.week_hours = 0;
+    }
+
+    //Starts a training course for the given position
+    //When it is done a course for another position retrains them into it, starting from half their rating
+    pub fn start_course(&mut self, posit: i64) {
+        self.course = Some(Course { posit, days_left: COURSE_DAYS });
+    }
+
+    //Learns from the given day of the week
+    //A day on a course counts down the course, finishing it when no days are left,
+    //otherwise every shift worked counts as experience, and the better they are the more shifts a point takes
+    //When their rating goes up and they are paid less than it is worth they ask for a raise, see calc_fair_wage
+    //Returns how the employee got better, if they did
+    pub fn learn(&mut self, weekday: i64) -> Option<Progress> {
+        let trained = match &mut self.course {
+            Some(course) => {
+                course.days_left -= 1;
+                if course.days_left > 0 {
+                    return None;
+                }
+                if course.posit != self.posit {
+                    self.posit = course.posit;
+                    self.rating = (self.rating / 2).max(1);
+                }
+                self.set_rating(self.rating + COURSE_GAIN);
+                true
+            }
+            None => {
+                self.experience += self.get_day_hours(weekday) / SHIFT_HOURS;
+                if self.rating >= MAX_RATING || self.experience < self.get_shifts_to_improve() {
+                    return None;
+                }
+                self.experience -= self.get_shifts_to_improve();
+                self.set_rating(self.rating + 1);
+                false
+            }
+        };
+        self.course = None;
+        //A course for another position can lower the rating, so an earlier request may no longer stand
+        let fair_wage = Employee::calc_fair_wage(self.rating);
+        self.asked_wage = if self.wage < fair_wage { Some(fair_wage) } else { None };
+        Some(Progress {
+            name: self.get_name(),
+            posit: self.posit,
+            rating: self.rating,
+            trained,
+            asked_wage: self.asked_wage,
+        })
+    }
+
+    //Changes morale at the end of a day
+    //Load is the customers that came in while the employee worked over what their stage could handle
+    //Morale goes up when they are paid more than their rating is worth, get a day off or have an easy day,
+    //and down when they are underpaid, swamped or work too many days in a row
+    //It also drifts back toward where it started, so it settles where the job keeps it
+    pub fn update_morale(&mut self, worked: bool, load: f64) {
+        let wage_ratio = self.wage.to_f64() / Employee::calc_fair_wage(self.rating).to_f64();
+        let mut change = ((wage_ratio - 1.0) * 10.0 * WAGE_EFFECT).clamp(-MAX_WAGE_EFFECT, MAX_WAGE_EFFECT);
+        if worked {
+            self.days_straight += 1;
+            change -= ((load - 1.0).max(0.0) * LOAD_EFFECT).min(MAX_LOAD_EFFECT);
+            let extra_days = (self.days_straight - MAX_DAYS_STRAIGHT).max(0);
+            change -= (extra_days as f64 * STREAK_EFFECT).min(MAX_STREAK_EFFECT);
+        } else {
+            self.days_straight = 0;
+            change += DAY_OFF_EFFECT;
+        }
+        change += (BASE_MORALE - self.morale) * MORALE_DRIFT;
+        self.morale = (self.morale + change).clamp(0.0, 100.0);
+    }
+}
+
+#[cfg(test)]
+use crate::simulator::GameRng;
+
+#[test]
+fn employee_test() {
+    let emp_test = Employee::rand_empl(&mut GameRng::new(1));
+
+    assert!(emp_test.wage >= MINWAGE);
+    assert!(emp_test.rating > 0 && emp_test.rating < 11);
+    assert!(emp_test.posit >= COOK && emp_test.posit <= HOST);
+}
+
+#[test]
+fn set_wage_test() {
+    let mut emp_test = Employee::rand_empl(&mut GameRng::new(1));
+    Employee::set_wage(&mut emp_test, Money::from_cents(1234));
+    assert_eq!(Money::from_cents(1234), emp_test.wage);
+}
+
+#[test]
+fn set_rating_test() {
+    let mut emp_test = Employee::rand_empl(&mut GameRng::new(1));
+    Employee::set_rating(&mut emp_test, 8);
+    assert_eq!(8, emp_test.rating);
+}
+
+#[test]
+fn set_rating_test_over_ten() {
+    let mut emp_test = Employee::rand_empl(&mut GameRng::new(1));
+    Employee::set_rating(&mut emp_test, 11);
+    assert_eq!(10, emp_test.rating);
+}
+
+#[test]
+fn set_rating_test_under_one() {
+    let mut emp_test = Employee::rand_empl(&mut GameRng::new(1));
+    Employee::set_rating(&mut emp_test, 0);
+    assert_eq!(1, emp_test.rating);
+}
+
+#[test]
+fn shift_test() {
+    let mut emp_test = Employee::default(COOK, 1, &mut GameRng::new(1));
+    assert_eq!(56, emp_test.get_sched_hours());
+    assert!(!emp_test.add_shift(0, Shift::Morning));
+    assert!(emp_test.remove_shift(0, Shift::Lunch));
+    assert!(!emp_test.remove_shift(0, Shift::Lunch));
+    assert!(emp_test.add_shift(0, Shift::Morning));
+    assert!(emp_test.works(0, Shift::Morning));
+    assert!(!emp_test.works(0, Shift::Lunch));
+}
+
+#[test]
+fn fair_wage_test() {
+    assert_eq!(MINWAGE, Employee::calc_fair_wage(3));
+    assert_eq!(MINWAGE + Money::from_dollars(3), Employee::calc_fair_wage(8));
+}
+
+#[test]
+fn morale_test() {
+    let mut emp_test = Employee::default(COOK, 1, &mut GameRng::new(1));
+    //A fair wage and an easy week with a day off keeps morale where it is
+    for _ in 0..6 {
+        emp_test.update_morale(true, 0.5);
+    }
+    emp_test.update_morale(false, 0.0);
+    assert!(emp_test.get_morale() >= BASE_MORALE);
+    assert_eq!(0, emp_test.get_days_straight());
+
+    //Underpaid, swamped and never a day off wears them down until they may quit
+    emp_test.set_wage(Money::from_dollars(5));
+    for _ in 0..30 {
+        emp_test.update_morale(true, 3.0);
+    }
+    assert!(emp_test.get_morale() < QUIT_MORALE);
+    assert!(emp_test.get_effective_rating() < emp_test.get_rating());
+    assert!(emp_test.calc_quit_chance() > 0.0);
+
+    //A raise and time off bring them back
+    emp_test.set_wage(Money::from_dollars(10));
+    for _ in 0..30 {
+        emp_test.update_morale(false, 0.0);
+    }
+    assert!(emp_test.get_morale() > CONTENT_MORALE);
+    assert_eq!(emp_test.get_rating(), emp_test.get_effective_rating());
+    assert_eq!(0.0, emp_test.calc_quit_chance());
+}
+
+#[test]
+fn experience_test() {
+    let mut emp_test = Employee::default(COOK, 1, &mut GameRng::new(1));
+    //Two shifts a day, 50 shifts to go from 5 to 6
+    for _ in 0..24 {
+        assert_eq!(None, emp_test.learn(0));
+    }
+    let progress = emp_test.learn(0).unwrap();
+    assert_eq!(6, progress.rating);
+    assert!(!progress.trained);
+    assert_eq!(Some(Employee::calc_fair_wage(6)), progress.asked_wage);
+    assert_eq!(0, emp_test.get_experience());
+    emp_test.set_wage(Employee::calc_fair_wage(6));
+    assert_eq!(None, emp_test.get_asked_wage());
+}
+
+#[test]
+fn course_test() {
+    let mut emp_test = Employee::default(COOK, 1, &mut GameRng::new(1));
+    emp_test.start_course(COOK);
+    assert!(emp_test.is_training());
+    assert_eq!(None, emp_test.learn(0));
+    assert_eq!(None, emp_test.learn(1));
+    let progress = emp_test.learn(2).unwrap();
+    assert!(progress.trained);
+    assert_eq!(5 + COURSE_GAIN, emp_test.get_rating());
+    assert!(!emp_test.is_training());
+    //Retraining for another position starts from half the rating
+    emp_test.start_course(HOST);
+    for weekday in 0..COURSE_DAYS {
+        emp_test.learn(weekday);
+    }
+    assert_eq!(HOST, emp_test.get_posit());
+    assert_eq!(3 + COURSE_GAIN, emp_test.get_rating());
+    assert_eq!(Some(SERVER), parse_posit("server"));
+    assert_eq!(None, parse_posit("chef"));
+}
+
+#[test]
+fn overtime_test() {
+    let mut emp_test = Employee::default(COOK, 1, &mut GameRng::new(1));
+    emp_test.set_wage(Money::from_dollars(10));
+    for weekday in 0..5 {
+        assert_eq!(Money::from_dollars(80), emp_test.calc_day_pay(weekday));
+        emp_test.work_day(weekday);
+    }
+    //Everything past 40 hours is overtime
+    assert_eq!(8, emp_test.get_overtime(5));
+    assert_eq!(Money::from_dollars(120), emp_test.calc_day_pay(5));
+    emp_test.reset_week();
+    assert_eq!(0, emp_test.get_overtime(5));
+}
@@ -6,13 +6,13 @@
 //!
 //! # Example: painless defaults
 //!
-//! The easiest way to get started is to use the default `Generator` to return
-//! a name:
+//! The easiest way to get started is to use a `Generator` with the default
+//! naming to return a name:
 //!
-//! ```
+//! ```ignore
 //! use names::Generator;
 //!
-//! let mut generator: Generator = Default::default();
+//! let mut generator = Generator::with_naming(Default::default(), rand::thread_rng());
 //! println!("Your project is: {}", generator.next().unwrap());
 //! // #=> "Your project is: rusty-nail"
 //! ```
@@ -20,10 +20,10 @@
 //! If more randomness is required, you can generate a name with a trailing
 //! 4-digit number:
 //!
-//! ```
+//! ```ignore
 //! use names::{Generator, Name};
 //!
-//! let mut generator = Generator::with_naming(Name::Numbered);
+//! let mut generator = Generator::with_naming(Name::Numbered, rand::thread_rng());
 //! println!("Your project is: {}", generator.next().unwrap());
 //! // #=> "Your project is: pushy-pencil-5602"
 //! ```
@@ -34,12 +34,12 @@
 //! you can provide your own by supplying 2 string slices. For example,
 //! this returns only one result:
 //!
-//! ```
+//! ```ignore
 //! use names::Generator;
 //!
 //! let adjectives = &["imaginary"];
 //! let nouns = &["roll"];
-//! let mut generator = Generator::new(adjectives, nouns, Default::default());
+//! let mut generator = Generator::new(adjectives, nouns, Default::default(), rand::thread_rng());
 //!
 //! assert_eq!("imaginary-roll", generator.next().unwrap());
 //! ```
@@ -52,94 +52,93 @@ mod first_name;
 mod last_name;
 
 /// A naming strategy for the `Generator`
+#[derive(Default)]
 pub enum Name {
     /// This represents a plain naming strategy of the form `"ADJECTIVE-NOUN"`
+    #[default]
     Plain,
     /// This represents a naming strategy with a random number appended to the
     /// end, of the form `"ADJECTIVE-NOUN-NUMBER"`
+    #[allow(dead_code)]
     Numbered
 }
 
-impl Default for Name {
-    fn default() -> Name { Name::Plain }
-}
-
 /// A random name generator which combines an adjective, a noun, and an
 /// optional number
 ///
 /// A `Generator` takes a slice of adjective and noun words strings and has
 /// a naming strategy (with or without a number appended).
-pub struct Generator<'a> {
-    firstName: &'a [&'a str],
-    lastName: &'a [&'a str],
+/// It draws from the random number generator it is given, so the names it
+/// produces can be reproduced from the same seed.
+pub struct Generator<'a, R: Rng> {
+    first_name: &'a [&'a str],
+    last_name: &'a [&'a str],
     naming: Name,
+    rng: R,
 }
 
-impl<'a> Generator<'a> {
-    /// Constructs a new `Generator<'a>`
+impl<'a, R: Rng> Generator<'a, R> {
+    /// Constructs a new `Generator<'a, R>`
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// use names::{Generator, Name};
     ///
     /// let adjectives = &["sassy"];
     /// let nouns = &["clocks"];
     /// let naming = Name::Plain;
     ///
-    /// let mut generator = Generator::new(adjectives, nouns, naming);
+    /// let mut generator = Generator::new(adjectives, nouns, naming, rand::thread_rng());
     ///
     /// assert_eq!("sassy-clocks", generator.next().unwrap());
     /// ```
     pub fn new(
-        firstName: &'a [&'a str],
-        lastName: &'a [&'a str],
-        naming: Name
-    ) -> Generator<'a> {
+        first_name: &'a [&'a str],
+        last_name: &'a [&'a str],
+        naming: Name,
+        rng: R
+    ) -> Generator<'a, R> {
         Generator {
-            firstName,
-            lastName,
-            naming: naming,
+            first_name,
+            last_name,
+            naming,
+            rng,
         }
     }
 
-    /// Construct and returns a default `Generator<'a>` containing a large
+    /// Construct and returns a default `Generator<'a, R>` containing a large
     /// collection of adjectives and nouns
     ///
-    /// ```
+    /// ```ignore
     /// use names::{Generator, Name};
     ///
-    /// let mut generator = Generator::with_naming(Name::Plain);
+    /// let mut generator = Generator::with_naming(Name::Plain, rand::thread_rng());
     ///
     /// println!("My new name is: {}", generator.next().unwrap());
     /// ```
-    pub fn with_naming(naming: Name) -> Generator<'a> {
+    pub fn with_naming(naming: Name, rng: R) -> Generator<'a, R> {
         Generator::new(
             first_name::LIST,
             last_name::LIST,
-            naming)
+            naming,
+            rng)
     }
 
-    fn rand_first(&self) -> &str {
-        rand::thread_rng().choose(self.firstName).unwrap()
+    fn rand_first(&mut self) -> &'a str {
+        self.rng.choose(self.first_name).unwrap()
     }
 
-    fn rand_last(&self) -> &str {
-        rand::thread_rng().choose(self.lastName).unwrap()
+    fn rand_last(&mut self) -> &'a str {
+        self.rng.choose(self.last_name).unwrap()
     }
 
-    fn rand_num(&self) -> u16 {
-        rand::thread_rng().gen_range(1, 10000)
-    }
-}
-
-impl<'a> Default for Generator<'a> {
-    fn default() -> Generator<'a> {
-        Generator::new(first_name::LIST, last_name::LIST, Default::default())
+    fn rand_num(&mut self) -> u16 {
+        self.rng.gen_range(1, 10000)
     }
 }
 
-impl<'a> Iterator for Generator<'a> {
+impl<'a, R: Rng> Iterator for Generator<'a, R> {
     type Item = String;
 
     fn next(&mut self) -> Option<String> {
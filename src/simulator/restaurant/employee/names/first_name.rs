@@ -1,7 +1,7 @@
 //First Names
 //Modified list from : https://github.com/fnichol/names/blob/master/data/adjectives.txt
 //List of first names was found on: https://www.ssa.gov/oact/babynames/decades/century.html
-pub const LIST: &'static [&'static str] = &[
+pub const LIST: &[&str] = &[
     "Jacob",
     "Emily",
     "Michael",
@@ -1,7 +1,7 @@
 //Last Names
 //Modified list from : https://github.com/fnichol/names/blob/master/data/adjectives.txt
 //List of last names was found on : https://names.mongabay.com/most_common_surnames.htm
-pub const LIST: &'static [&'static str] = &[
+pub const LIST: &[&str] = &[
     "Smith",
     "Johnson",
     "Williams",
@@ -0,0 +1,274 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following code is used to represent an ingredient the restaurant keeps in stock.
+// Menu items are made from ingredients, see the recipe on MenuItem.
+// An ingredient has a name, the quality the next order is bought at and an inventory.
+// It also knows what one unit costs the restaurant to buy at each quality level,
+// and how many days it keeps at each quality level.
+// The inventory is a list of batches, one for every delivery, oldest first.
+// Every batch keeps the quality it was bought at, so changing the quality
+// only changes what is bought next, not the stock already on hand.
+// Stock is used first in, first out, and a batch that is kept past its
+// shelf life spoils and is thrown away.
+// Like a menu item, an ingredient just manages its own data.
+
+use crate::simulator::Money;
+use serde::{Deserialize, Serialize};
+
+// Units of an ingredient that arrived together
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Batch {
+    quantity: i64,
+    quality: i64,
+    //What one unit cost when it was bought
+    unit_cost: Money,
+    //The batch is thrown away at the end of this day, None if it never spoils
+    spoils_on: Option<i64>,
+}
+
+impl Batch {
+    pub fn get_quantity(&self) -> i64 {
+        self.quantity
+    }
+
+    pub fn get_quality(&self) -> i64 {
+        self.quality
+    }
+
+    pub fn get_unit_cost(&self) -> Money {
+        self.unit_cost
+    }
+
+    pub fn get_spoils_on(&self) -> Option<i64> {
+        self.spoils_on
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Ingredient {
+    name: String,
+    quality: i64,
+    //Cost to the restaurant of one unit at quality 1, 2 and 3
+    supply_cost: [Money; 3],
+    //Days a unit keeps at quality 1, 2 and 3, None if it never spoils
+    shelf_life: Option<[i64; 3]>,
+    batches: Vec<Batch>,
+}
+
+impl Ingredient {
+    //The starting inventory arrives on day 0
+    pub fn new(
+        name: String,
+        quality: i64,
+        supply_cost: [Money; 3],
+        shelf_life: Option<[i64; 3]>,
+        inv: i64,
+    ) -> Ingredient {
+        let mut ingredient = Ingredient {
+            name,
+            quality,
+            supply_cost,
+            shelf_life,
+            batches: Vec::new(),
+        };
+        ingredient.inc_inv(inv, 0);
+        ingredient
+    }
+
+    //Getters
+    pub fn get_name(&self) -> String {
+        self.name.to_owned()
+    }
+
+    pub fn get_quality(&self) -> i64 {
+        self.quality
+    }
+
+    //Cost to the restaurant of one unit at the ingredient's current quality
+    pub fn get_supply_cost(&self) -> Money {
+        self.supply_cost[(self.quality.clamp(1, 3) - 1) as usize]
+    }
+
+    pub fn get_supply_costs(&self) -> [Money; 3] {
+        self.supply_cost
+    }
+
+    //Days a unit keeps at the ingredient's current quality
+    pub fn get_shelf_life(&self) -> Option<i64> {
+        self.shelf_life
+            .map(|shelf_life| shelf_life[(self.quality.clamp(1, 3) - 1) as usize])
+    }
+
+    pub fn get_shelf_lives(&self) -> Option<[i64; 3]> {
+        self.shelf_life
+    }
+
+    pub fn get_batches(&self) -> &[Batch] {
+        &self.batches
+    }
+
+    //Average quality of the stock on hand, weighted by the size of each batch
+    //None if there is no stock
+    pub fn get_stock_quality(&self) -> Option<f64> {
+        let inv = self.get_inv();
+        if inv <= 0 {
+            return None;
+        }
+        let total: i64 = self
+            .batches
+            .iter()
+            .map(|batch| batch.quantity * batch.quality)
+            .sum();
+        Some(total as f64 / inv as f64)
+    }
+
+    //Total units in stock across all batches
+    pub fn get_inv(&self) -> i64 {
+        self.batches.iter().map(|batch| batch.quantity).sum()
+    }
+
+    //What the units in stock cost to buy
+    pub fn get_value(&self) -> Money {
+        self.batches
+            .iter()
+            .map(|batch| batch.unit_cost * batch.quantity)
+            .sum()
+    }
+
+    //Setters
+    pub fn set_quality(&mut self, new_quality: i64) {
+        self.quality = new_quality;
+    }
+
+    //Increases Inventory by the given amount.
+    //The units arrive as a new batch on the given day, at the current quality.
+    //Allows the user to order more of an ingredient.
+    pub fn inc_inv(&mut self, inc_amount: i64, day: i64) {
+        self.add_batch(inc_amount, self.quality, self.get_supply_cost(), day);
+    }
+
+    //Adds a batch of the given quality, bought at the given price per unit, that arrived on the given day
+    pub fn add_batch(&mut self, quantity: i64, quality: i64, unit_cost: Money, day: i64) {
+        if quantity <= 0 {
+            return;
+        }
+        let shelf_life = self
+            .shelf_life
+            .map(|shelf_life| shelf_life[(quality.clamp(1, 3) - 1) as usize]);
+        self.batches.push(Batch {
+            quantity,
+            quality,
+            unit_cost,
+            spoils_on: shelf_life.map(|shelf_life| day + shelf_life),
+        });
+    }
+
+    //Decrease Inventory
+    //Takes from the oldest batch first.
+    //Used when a menu item is made from this ingredient.
+    //Returns the lowest quality of the units taken, None if nothing was taken
+    pub fn dec_inv(&mut self, dec_amount: i64) -> Option<i64> {
+        let mut left = dec_amount;
+        let mut quality: Option<i64> = None;
+        for batch in &mut self.batches {
+            if left <= 0 {
+                break;
+            }
+            let taken = left.min(batch.quantity);
+            if taken > 0 {
+                quality = Some(quality.map_or(batch.quality, |q| q.min(batch.quality)));
+            }
+            batch.quantity -= taken;
+            left -= taken;
+        }
+        self.batches.retain(|batch| batch.quantity > 0);
+        quality
+    }
+
+    //Throws away every batch that spoils on or before the given day
+    //Returns the units thrown away and what they cost
+    pub fn spoil(&mut self, day: i64) -> (i64, Money) {
+        let mut units = 0;
+        let mut cost = Money::ZERO;
+        for batch in &self.batches {
+            if batch.spoils_on.is_some_and(|spoils_on| spoils_on <= day) {
+                units += batch.quantity;
+                cost += batch.unit_cost * batch.quantity;
+            }
+        }
+        self.batches
+            .retain(|batch| batch.spoils_on.map_or(true, |spoils_on| spoils_on > day));
+        (units, cost)
+    }
+}
+
+#[cfg(test)]
+const TEST_COSTS: [Money; 3] = [Money::from_cents(100), Money::from_cents(200), Money::from_cents(300)];
+
+#[test]
+fn inc_inv_test() {
+    let mut ingredient_test = Ingredient::new("Test".to_owned(), 1, TEST_COSTS, None, 0);
+    Ingredient::inc_inv(&mut ingredient_test, 25, 1);
+    assert_eq!(25, ingredient_test.get_inv());
+}
+
+#[test]
+fn dec_inv_test() {
+    let mut ingredient_test = Ingredient::new("Test".to_owned(), 1, TEST_COSTS, None, 25);
+    Ingredient::dec_inv(&mut ingredient_test, 25);
+    assert_eq!(0, ingredient_test.get_inv());
+}
+
+#[test]
+fn dec_inv_test_zero() {
+    let mut ingredient_test = Ingredient::new("Test".to_owned(), 1, TEST_COSTS, None, 0);
+    Ingredient::dec_inv(&mut ingredient_test, 25);
+    assert_eq!(0, ingredient_test.get_inv());
+}
+
+#[test]
+fn supply_cost_test() {
+    let mut ingredient_test = Ingredient::new("Test".to_owned(), 1, TEST_COSTS, None, 100);
+    assert_eq!(Money::from_dollars(1), ingredient_test.get_supply_cost());
+    ingredient_test.set_quality(3);
+    assert_eq!(Money::from_dollars(3), ingredient_test.get_supply_cost());
+}
+
+#[test]
+fn fifo_test() {
+    let mut ingredient_test = Ingredient::new("Test".to_owned(), 1, TEST_COSTS, Some([2, 3, 4]), 10);
+    ingredient_test.inc_inv(10, 1);
+    ingredient_test.dec_inv(15);
+    //What's left is from the newer batch
+    assert_eq!(1, ingredient_test.get_batches().len());
+    assert_eq!(Some(3), ingredient_test.get_batches()[0].get_spoils_on());
+}
+
+#[test]
+fn spoil_test() {
+    let mut ingredient_test = Ingredient::new("Test".to_owned(), 1, TEST_COSTS, Some([2, 3, 4]), 10);
+    ingredient_test.set_quality(2);
+    ingredient_test.inc_inv(5, 1);
+    assert_eq!((0, Money::ZERO), ingredient_test.spoil(1));
+    //The starting batch keeps for 2 days, the better batch for 3
+    assert_eq!((10, Money::from_dollars(10)), ingredient_test.spoil(2));
+    assert_eq!(5, ingredient_test.get_inv());
+    assert_eq!((5, Money::from_dollars(10)), ingredient_test.spoil(4));
+    assert_eq!(0, ingredient_test.get_inv());
+}
+
+#[test]
+fn batch_quality_test() {
+    let mut ingredient_test = Ingredient::new("Test".to_owned(), 1, TEST_COSTS, None, 50);
+    ingredient_test.set_quality(3);
+    //Changing the quality doesn't relabel the stock already on hand
+    assert_eq!(Some(1.0), ingredient_test.get_stock_quality());
+    ingredient_test.inc_inv(50, 1);
+    assert_eq!(Some(2.0), ingredient_test.get_stock_quality());
+    assert_eq!(Some(1), ingredient_test.dec_inv(50));
+    assert_eq!(Some(3), ingredient_test.dec_inv(10));
+    assert_eq!(Some(3.0), ingredient_test.get_stock_quality());
+}
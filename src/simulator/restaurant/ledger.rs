@@ -0,0 +1,543 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following is the restaurant's books.
+// Every time money or stock changes hands it is written down as an entry in the ledger.
+// Entries are double-entry: every entry moves an amount out of one account and into another,
+// so the accounts always balance. An entry debits the account the value goes to
+// and credits the account it comes from:
+//     a sale debits Cash and credits Sales,
+//     buying stock debits Inventory and credits Cash,
+//     using stock to make an item debits Cost of goods and credits Inventory,
+//     paying wages debits Payroll and credits Cash,
+//     borrowing debits Cash and credits Loans,
+//     sales tax collected debits Cash and credits Sales tax, which is owed until it is paid over.
+// Every entry also has the day it happened on, a category saying what kind of transaction it was
+// and a short memo.
+// Statements sum up the entries of a day, a week or a month:
+// the profit and loss statement is the revenue earned less the expenses that earned it,
+// and the cash flow statement is the cash that came in and went out.
+// Stock counts as an expense when it is used or spoils, not when it is bought,
+// so the profit of a day and the change in cash don't have to match.
+// Entries made before day 1, such as the starting cash, only count toward opening balances.
+
+use super::schedule;
+use crate::simulator::Money;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// Length of a month in days, a month is four weeks and the first one starts on day 1
+pub const MONTH_DAYS: i64 = 28;
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Account {
+    Cash,
+    Inventory,
+    Equity,
+    Sales,
+    CostOfGoods,
+    Payroll,
+    Waste,
+    Loans,
+    CreditLine,
+    Interest,
+    SalesTax,
+    Rent,
+    Utilities,
+    Insurance,
+    IncomeTax,
+    Training,
+}
+
+// Every account in the order they are reported
+pub const ACCOUNTS: [Account; 16] = [
+    Account::Cash,
+    Account::Inventory,
+    Account::Loans,
+    Account::CreditLine,
+    Account::SalesTax,
+    Account::Equity,
+    Account::Sales,
+    Account::CostOfGoods,
+    Account::Payroll,
+    Account::Waste,
+    Account::Rent,
+    Account::Utilities,
+    Account::Insurance,
+    Account::Training,
+    Account::Interest,
+    Account::IncomeTax,
+];
+
+// What an account keeps track of
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Kind {
+    //What the restaurant owns
+    Asset,
+    //What the restaurant owes
+    Liability,
+    //What the owner put in
+    Equity,
+    //What the restaurant earns
+    Revenue,
+    //What it costs to run the restaurant
+    Expense,
+}
+
+impl Account {
+    pub fn get_name(self) -> String {
+        match self {
+            Account::Cash => "Cash".to_owned(),
+            Account::Inventory => "Inventory".to_owned(),
+            Account::Equity => "Owner's equity".to_owned(),
+            Account::Sales => "Sales".to_owned(),
+            Account::CostOfGoods => "Cost of goods".to_owned(),
+            Account::Payroll => "Payroll".to_owned(),
+            Account::Waste => "Waste".to_owned(),
+            Account::Loans => "Loans".to_owned(),
+            Account::CreditLine => "Credit line".to_owned(),
+            Account::Interest => "Interest".to_owned(),
+            Account::SalesTax => "Sales tax".to_owned(),
+            Account::Rent => "Rent".to_owned(),
+            Account::Utilities => "Utilities".to_owned(),
+            Account::Insurance => "Insurance".to_owned(),
+            Account::IncomeTax => "Income tax".to_owned(),
+            Account::Training => "Training".to_owned(),
+        }
+    }
+
+    pub fn get_kind(self) -> Kind {
+        match self {
+            Account::Cash | Account::Inventory => Kind::Asset,
+            Account::Loans | Account::CreditLine | Account::SalesTax => Kind::Liability,
+            Account::Equity => Kind::Equity,
+            Account::Sales => Kind::Revenue,
+            Account::CostOfGoods
+            | Account::Payroll
+            | Account::Waste
+            | Account::Rent
+            | Account::Utilities
+            | Account::Insurance
+            | Account::Training
+            | Account::Interest
+            | Account::IncomeTax => Kind::Expense,
+        }
+    }
+
+    //Place of the account in ACCOUNTS
+    fn get_index(self) -> usize {
+        ACCOUNTS.iter().position(|account| *account == self).expect("every account is listed")
+    }
+
+    //Whether a debit adds to the account, true for assets and expenses
+    fn debit_normal(self) -> bool {
+        match self.get_kind() {
+            Kind::Asset | Kind::Expense => true,
+            Kind::Liability | Kind::Equity | Kind::Revenue => false,
+        }
+    }
+}
+
+// What kind of transaction an entry is
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Category {
+    //Money or stock the owner put in
+    Capital,
+    Sale,
+    Payroll,
+    //Stock bought from a supplier
+    Purchase,
+    //Money given back by a supplier for stock that never arrived
+    Refund,
+    //Stock used to make menu items
+    Usage,
+    //Stock thrown away because it spoiled
+    Spoilage,
+    //Money lent by the bank
+    Borrowing,
+    //Money paid back to the bank
+    Repayment,
+    //Interest and late fees charged by the bank
+    Interest,
+    //Rent, utilities and insurance
+    Overhead,
+    //Sales tax collected and paid over, and income tax
+    Tax,
+    //Training courses employees were sent on
+    Training,
+}
+
+// Every category in the order they are reported
+pub const CATEGORIES: [Category; 13] = [
+    Category::Capital,
+    Category::Sale,
+    Category::Payroll,
+    Category::Purchase,
+    Category::Refund,
+    Category::Usage,
+    Category::Spoilage,
+    Category::Borrowing,
+    Category::Repayment,
+    Category::Interest,
+    Category::Overhead,
+    Category::Tax,
+    Category::Training,
+];
+
+impl Category {
+    pub fn get_name(self) -> String {
+        match self {
+            Category::Capital => "Capital".to_owned(),
+            Category::Sale => "Sales".to_owned(),
+            Category::Payroll => "Payroll".to_owned(),
+            Category::Purchase => "Stock purchases".to_owned(),
+            Category::Refund => "Supplier refunds".to_owned(),
+            Category::Usage => "Stock used".to_owned(),
+            Category::Spoilage => "Spoiled stock".to_owned(),
+            Category::Borrowing => "Borrowing".to_owned(),
+            Category::Repayment => "Repayments".to_owned(),
+            Category::Interest => "Interest".to_owned(),
+            Category::Overhead => "Operating costs".to_owned(),
+            Category::Tax => "Taxes".to_owned(),
+            Category::Training => "Training".to_owned(),
+        }
+    }
+}
+
+// One transaction, moving the amount from the credit account to the debit account
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: i64,
+    pub category: Category,
+    pub debit: Account,
+    pub credit: Account,
+    pub amount: Money,
+    pub memo: String,
+}
+
+// The stretch of days a statement covers
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Period {
+    Day,
+    Week,
+    Month,
+}
+
+impl Period {
+    pub fn get_name(self) -> String {
+        match self {
+            Period::Day => "Daily".to_owned(),
+            Period::Week => "Weekly".to_owned(),
+            Period::Month => "Monthly".to_owned(),
+        }
+    }
+
+    //First and last day of the period the given day falls in
+    //Weeks start on Monday
+    pub fn get_days(self, day: i64) -> (i64, i64) {
+        match self {
+            Period::Day => (day, day),
+            Period::Week => {
+                let first = day - schedule::get_weekday(day);
+                (first, first + 6)
+            }
+            Period::Month => {
+                let first = (day - 1).div_euclid(MONTH_DAYS) * MONTH_DAYS + 1;
+                (first, first + MONTH_DAYS - 1)
+            }
+        }
+    }
+}
+
+// Profit and loss and cash flow over a stretch of days
+pub struct Statement {
+    pub first_day: i64,
+    pub last_day: i64,
+    //Earned by every revenue account, in account order
+    pub revenue: Vec<(Account, Money)>,
+    //Spent by every expense account, in account order
+    pub expenses: Vec<(Account, Money)>,
+    //Revenue less expenses
+    pub net_income: Money,
+    //Cash at the start of the first day
+    pub opening_cash: Money,
+    //Cash that came in and went out for every category, in category order
+    pub cash_in: Vec<(Category, Money)>,
+    pub cash_out: Vec<(Category, Money)>,
+    //Cash at the end of the last day
+    pub closing_cash: Money,
+}
+
+impl Statement {
+    //Amount earned or spent by the given revenue or expense account
+    pub fn get_amount(&self, account: Account) -> Money {
+        self.revenue
+            .iter()
+            .chain(&self.expenses)
+            .find(|(other, _)| *other == account)
+            .map_or(Money::ZERO, |(_, amount)| *amount)
+    }
+
+    pub fn get_tot_revenue(&self) -> Money {
+        self.revenue.iter().map(|(_, amount)| amount).sum()
+    }
+
+    pub fn get_tot_expenses(&self) -> Money {
+        self.expenses.iter().map(|(_, amount)| amount).sum()
+    }
+
+    //Change in cash over the period
+    pub fn get_net_cash(&self) -> Money {
+        self.closing_cash - self.opening_cash
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(from = "LedgerFile")]
+pub struct Ledger {
+    //Every entry in the order they were made
+    entries: Vec<Entry>,
+    //Balance of every account over every entry, in account order
+    //Kept up to date as entries are made so balances don't have to add up the whole ledger
+    #[serde(skip)]
+    balances: Vec<Money>,
+    //Positions of the entries made on every day, so a statement only reads the days it covers
+    #[serde(skip)]
+    days: BTreeMap<i64, Vec<usize>>,
+}
+
+// What a ledger is saved as, only the entries
+// The balances and the day index are worked out again when it is loaded
+#[derive(Deserialize)]
+struct LedgerFile {
+    entries: Vec<Entry>,
+}
+
+impl From<LedgerFile> for Ledger {
+    fn from(file: LedgerFile) -> Ledger {
+        let mut ledger = Ledger::new();
+        for entry in file.entries {
+            ledger.push(entry);
+        }
+        ledger
+    }
+}
+
+impl Default for Ledger {
+    fn default() -> Ledger {
+        Ledger::new()
+    }
+}
+
+impl Ledger {
+    pub fn new() -> Ledger {
+        Ledger {
+            entries: Vec::new(),
+            balances: vec![Money::ZERO; ACCOUNTS.len()],
+            days: BTreeMap::new(),
+        }
+    }
+
+    //Getters
+    pub fn get_entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    //Entries made on the given day
+    pub fn get_day_entries(&self, day: i64) -> Vec<&Entry> {
+        self.get_range_entries(day, day).collect()
+    }
+
+    //Balance of the account over every entry, in the account's normal direction
+    pub fn get_balance(&self, account: Account) -> Money {
+        self.balances[account.get_index()]
+    }
+
+    //Writes down a transaction
+    //Nothing is written for an amount of zero
+    pub fn record(&mut self, day: i64, category: Category, debit: Account, credit: Account, amount: Money, memo: String) {
+        if amount.is_zero() {
+            return;
+        }
+        self.push(Entry {
+            day,
+            category,
+            debit,
+            credit,
+            amount,
+            memo,
+        });
+    }
+
+    //Sums up the entries from the first to the last day
+    pub fn calc_statement(&self, first_day: i64, last_day: i64) -> Statement {
+        let tot_by_kind = |kind: Kind| -> Vec<(Account, Money)> {
+            ACCOUNTS
+                .iter()
+                .filter(|account| account.get_kind() == kind)
+                .map(|account| (*account, calc_change(*account, self.get_range_entries(first_day, last_day))))
+                .collect()
+        };
+        let revenue = tot_by_kind(Kind::Revenue);
+        let expenses = tot_by_kind(Kind::Expense);
+        let net_income = revenue.iter().map(|(_, amount)| amount).sum::<Money>()
+            - expenses.iter().map(|(_, amount)| amount).sum::<Money>();
+
+        let cash_flow = |into_cash: bool| -> Vec<(Category, Money)> {
+            CATEGORIES
+                .iter()
+                .map(|category| {
+                    let amount = self
+                        .get_range_entries(first_day, last_day)
+                        .filter(|entry| entry.category == *category)
+                        .filter(|entry| {
+                            if into_cash {
+                                entry.debit == Account::Cash
+                            } else {
+                                entry.credit == Account::Cash
+                            }
+                        })
+                        .map(|entry| entry.amount)
+                        .sum();
+                    (*category, amount)
+                })
+                .collect()
+        };
+        //Worked back from the cash on hand, so only the entries from the first day on are read
+        let closing_cash = self.get_balance(Account::Cash)
+            - calc_change(Account::Cash, self.get_range_entries(last_day.saturating_add(1), i64::MAX));
+        let opening_cash = closing_cash - calc_change(Account::Cash, self.get_range_entries(first_day, last_day));
+
+        Statement {
+            first_day,
+            last_day,
+            revenue,
+            expenses,
+            net_income,
+            opening_cash,
+            cash_in: cash_flow(true),
+            cash_out: cash_flow(false),
+            closing_cash,
+        }
+    }
+
+    //Sums up the given period that the given day falls in
+    pub fn calc_period(&self, period: Period, day: i64) -> Statement {
+        let (first_day, last_day) = period.get_days(day);
+        self.calc_statement(first_day, last_day)
+    }
+
+    //Entries made from the first to the last day, in the order they were made on each day
+    fn get_range_entries(&self, first_day: i64, last_day: i64) -> impl Iterator<Item = &Entry> {
+        let days = if first_day <= last_day {
+            self.days.range(first_day..=last_day)
+        } else {
+            self.days.range(0..0)
+        };
+        days.flat_map(move |(_, positions)| positions.iter().map(move |pos| &self.entries[*pos]))
+    }
+
+    //Adds an entry to the ledger, its balances and its day index
+    fn push(&mut self, entry: Entry) {
+        for account in [entry.debit, entry.credit] {
+            self.balances[account.get_index()] += calc_change(account, std::iter::once(&entry));
+        }
+        self.days.entry(entry.day).or_default().push(self.entries.len());
+        self.entries.push(entry);
+    }
+}
+
+//Change in the account's balance over the given entries, in the account's normal direction
+fn calc_change<'a, I: Iterator<Item = &'a Entry>>(account: Account, entries: I) -> Money {
+    let sign = if account.debit_normal() { 1 } else { -1 };
+    entries
+        .map(|entry| {
+            let mut change = Money::ZERO;
+            if entry.debit == account {
+                change += entry.amount;
+            }
+            if entry.credit == account {
+                change -= entry.amount;
+            }
+            change * sign
+        })
+        .sum()
+}
+
+#[cfg(test)]
+fn test_ledger() -> Ledger {
+    let mut ledger = Ledger::new();
+    ledger.record(0, Category::Capital, Account::Cash, Account::Equity, Money::from_dollars(1000), "Starting cash".to_owned());
+    ledger.record(1, Category::Purchase, Account::Inventory, Account::Cash, Money::from_dollars(200), "Patty".to_owned());
+    ledger.record(1, Category::Sale, Account::Cash, Account::Sales, Money::from_dollars(50), "Burger".to_owned());
+    ledger.record(1, Category::Usage, Account::CostOfGoods, Account::Inventory, Money::from_dollars(20), "Burger".to_owned());
+    ledger.record(2, Category::Payroll, Account::Payroll, Account::Cash, Money::from_dollars(100), "Wages".to_owned());
+    ledger.record(2, Category::Borrowing, Account::Cash, Account::Loans, Money::from_dollars(300), "Loan 1".to_owned());
+    ledger.record(3, Category::Interest, Account::Interest, Account::Cash, Money::from_dollars(5), "Loan 1".to_owned());
+    ledger.record(3, Category::Repayment, Account::Loans, Account::Cash, Money::from_dollars(45), "Loan 1".to_owned());
+    ledger
+}
+
+#[test]
+fn balance_test() {
+    let ledger = test_ledger();
+    assert_eq!(Money::from_dollars(1000), ledger.get_balance(Account::Cash));
+    assert_eq!(Money::from_dollars(255), ledger.get_balance(Account::Loans));
+    assert_eq!(Money::from_dollars(180), ledger.get_balance(Account::Inventory));
+    assert_eq!(Money::from_dollars(50), ledger.get_balance(Account::Sales));
+    //Double entry keeps the books balanced
+    let debits: Money = ACCOUNTS
+        .iter()
+        .filter(|account| account.debit_normal())
+        .map(|account| ledger.get_balance(*account))
+        .sum();
+    let credits: Money = ACCOUNTS
+        .iter()
+        .filter(|account| !account.debit_normal())
+        .map(|account| ledger.get_balance(*account))
+        .sum();
+    assert_eq!(debits, credits);
+}
+
+#[test]
+fn statement_test() {
+    let ledger = test_ledger();
+    let day = ledger.calc_statement(1, 1);
+    assert_eq!(Money::from_dollars(30), day.net_income);
+    assert_eq!(Money::from_dollars(1000), day.opening_cash);
+    assert_eq!(Money::from_dollars(850), day.closing_cash);
+    assert_eq!(Money::from_dollars(-150), day.get_net_cash());
+    assert!(day.cash_out.contains(&(Category::Purchase, Money::from_dollars(200))));
+    //The starting cash only counts toward the opening balance
+    let week = ledger.calc_period(Period::Week, 3);
+    assert_eq!((1, 7), (week.first_day, week.last_day));
+    //Borrowing isn't income, interest is an expense
+    assert_eq!(Money::from_dollars(-75), week.net_income);
+    assert_eq!(Money::from_dollars(5), week.get_amount(Account::Interest));
+    assert_eq!(Money::ZERO, week.cash_in.iter().find(|(category, _)| *category == Category::Capital).unwrap().1);
+}
+
+#[test]
+fn load_test() {
+    let ledger = test_ledger();
+    //Only the entries are saved, the balances are worked out again on load
+    let saved = serde_json::to_value(&ledger).unwrap();
+    assert_eq!(vec!["entries"], saved.as_object().unwrap().keys().collect::<Vec<_>>());
+    let loaded: Ledger = serde_json::from_value(saved).unwrap();
+    for account in ACCOUNTS.iter() {
+        assert_eq!(ledger.get_balance(*account), loaded.get_balance(*account));
+    }
+    assert_eq!(2, loaded.get_day_entries(3).len());
+    assert_eq!(Money::from_dollars(1050), loaded.calc_statement(2, 2).closing_cash);
+    assert_eq!(Money::from_dollars(1000), loaded.calc_statement(4, 10).opening_cash);
+}
+
+#[test]
+fn period_test() {
+    assert_eq!((8, 14), Period::Week.get_days(10));
+    assert_eq!((1, 28), Period::Month.get_days(28));
+    assert_eq!((29, 56), Period::Month.get_days(29));
+    assert_eq!((5, 5), Period::Day.get_days(5));
+}
@@ -0,0 +1,605 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following reads a restaurant's menu from a file.
+// A menu file is TOML, or JSON if the file name ends in .json.
+// It lists the ingredients the restaurant stocks and the items made from them:
+//     [[ingredient]]
+//     name = "Patty"
+//     supply_cost = [2.00, 3.20, 4.40]
+//     shelf_life = [3, 4, 5]
+//     starting_inv = 100
+//
+//     [[item]]
+//     name = "Burger"
+//     category = "main"
+//     price = 5.00
+//     reference_price = 5.00
+//     recipe = { Bun = 1, Patty = 1 }
+//
+//     [[supplier]]
+//     name = "Metro Wholesale"
+//     prices = { Bun = [0.35, 0.55, 0.80], Patty = [1.40, 2.20, 3.10] }
+//     min_order = 50
+//     bulk_amount = 200
+//     bulk_discount = 0.15
+//     lead_time = 2
+//     reliability = 0.9
+//
+//     [[segment]]
+//     name = "Students"
+//     budget = [6.00, 15.00]
+//     likes = { side = 1.5, drink = 1.5 }
+//     quality_premium = 0.1
+//     price_sensitivity = 8.0
+//     party = [1, 3]
+//     slots = { Afternoon = 3.0, LateNight = 3.0 }
+//     days = [1.0, 1.0, 1.0, 1.0, 1.2, 0.6, 0.4]
+// shelf_life is how many days the ingredient keeps at each quality,
+// an ingredient without one never spoils.
+// An ingredient's quality can also be given and defaults to 1.
+// An item's reference price is what customers expect to pay for it, and defaults to its price.
+// Item prices are rounded to the nearest cent.
+// A supplier's min_order defaults to 1, and it has no bulk discount unless one is given.
+// A menu without suppliers gets a market that sells every ingredient at its supply cost right away.
+// Every customer orders one item from the first category in the file,
+// and one item from each other category half of the time, more or less often for the categories
+// their segment likes, see segment.
+// A segment's likes, party and slots can be left out, and days defaults to every day the same.
+// A menu without segments gets the ones in default_segments.toml.
+// The menu every new game starts with is default_menu.toml.
+
+use super::ingredient::Ingredient;
+use super::menu_item::{MenuItem, MAX_PRICE};
+use super::schedule::{Slot, SLOTS, WEEKDAYS};
+use super::segment::Segment;
+use super::supplier::Supplier;
+use crate::simulator::Money;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+
+const DEFAULT_MENU: &str = include_str!("default_menu.toml");
+const DEFAULT_SEGMENTS: &str = include_str!("default_segments.toml");
+
+// One ingredient as it is written in a menu file
+#[derive(Deserialize)]
+struct IngredientDef {
+    name: String,
+    supply_cost: [f64; 3],
+    #[serde(default)]
+    shelf_life: Option<[i64; 3]>,
+    starting_inv: i64,
+    #[serde(default = "default_quality")]
+    quality: i64,
+}
+
+fn default_quality() -> i64 {
+    1
+}
+
+// One item as it is written in a menu file
+#[derive(Deserialize)]
+struct ItemDef {
+    name: String,
+    category: String,
+    price: f64,
+    #[serde(default)]
+    reference_price: Option<f64>,
+    recipe: BTreeMap<String, i64>,
+}
+
+// One supplier as it is written in a menu file
+#[derive(Deserialize)]
+struct SupplierDef {
+    name: String,
+    prices: BTreeMap<String, [f64; 3]>,
+    #[serde(default = "default_min_order")]
+    min_order: i64,
+    #[serde(default)]
+    bulk_amount: Option<i64>,
+    #[serde(default)]
+    bulk_discount: f64,
+    lead_time: i64,
+    reliability: f64,
+}
+
+fn default_min_order() -> i64 {
+    1
+}
+
+// One customer segment as it is written in a menu file
+#[derive(Deserialize)]
+struct SegmentDef {
+    name: String,
+    budget: [f64; 2],
+    #[serde(default)]
+    likes: BTreeMap<String, f64>,
+    quality_premium: f64,
+    price_sensitivity: f64,
+    #[serde(default = "default_party")]
+    party: [i64; 2],
+    #[serde(default)]
+    slots: BTreeMap<String, f64>,
+    #[serde(default = "default_days")]
+    days: [f64; 7],
+}
+
+fn default_party() -> [i64; 2] {
+    [1, 1]
+}
+
+fn default_days() -> [f64; 7] {
+    [1.0; 7]
+}
+
+#[derive(Deserialize)]
+struct MenuDef {
+    ingredient: Vec<IngredientDef>,
+    item: Vec<ItemDef>,
+    #[serde(default)]
+    supplier: Vec<SupplierDef>,
+    #[serde(default)]
+    segment: Vec<SegmentDef>,
+}
+
+// The segments given to a menu that doesn't list any
+#[derive(Deserialize)]
+struct SegmentsDef {
+    segment: Vec<SegmentDef>,
+}
+
+// The menu items a restaurant sells, the ingredients they are made from,
+// the suppliers that sell those ingredients and the customers that come in
+pub struct Menu {
+    pub items: Vec<MenuItem>,
+    pub ingredients: Vec<Ingredient>,
+    pub suppliers: Vec<Supplier>,
+    pub segments: Vec<Segment>,
+}
+
+// Everything that can go wrong while reading a menu file
+#[derive(Debug)]
+pub enum MenuError {
+    Io(io::Error),
+    Format(String),
+    //The file could be read but the menu in it doesn't make sense
+    Invalid(String),
+}
+
+impl fmt::Display for MenuError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MenuError::Io(e) => write!(f, "{}", e),
+            MenuError::Format(e) => write!(f, "menu file is not valid ({})", e),
+            MenuError::Invalid(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<io::Error> for MenuError {
+    fn from(e: io::Error) -> MenuError {
+        MenuError::Io(e)
+    }
+}
+
+//The menu every new game starts with
+pub fn default_menu() -> Menu {
+    parse_toml(DEFAULT_MENU).expect("default menu is valid")
+}
+
+//Reads a menu from the given file
+pub fn load_menu(path: &str) -> Result<Menu, MenuError> {
+    let text = fs::read_to_string(path)?;
+    if path.ends_with(".json") {
+        parse_json(&text)
+    } else {
+        parse_toml(&text)
+    }
+}
+
+pub fn parse_toml(text: &str) -> Result<Menu, MenuError> {
+    let menu: MenuDef = toml::from_str(text).map_err(|e| MenuError::Format(e.to_string()))?;
+    build_menu(menu)
+}
+
+pub fn parse_json(text: &str) -> Result<Menu, MenuError> {
+    let menu: MenuDef =
+        serde_json::from_str(text).map_err(|e| MenuError::Format(e.to_string()))?;
+    build_menu(menu)
+}
+
+//Turns an amount in dollars read from a file into money
+//The error names what the amount was for, e.g. Burger has a price too large to hold
+fn to_money(dollars: f64, name: &str, what: &str) -> Result<Money, MenuError> {
+    Money::checked_from_f64(dollars)
+        .ok_or_else(|| MenuError::Invalid(format!("{} has {} too large to hold", name, what)))
+}
+
+//Turns the amounts for quality 1, 2 and 3 read from a file into money, see to_money
+fn to_prices(dollars: [f64; 3], name: &str, what: &str) -> Result<[Money; 3], MenuError> {
+    let mut prices = [Money::ZERO; 3];
+    for (price, dollars) in prices.iter_mut().zip(dollars) {
+        *price = to_money(dollars, name, what)?;
+    }
+    Ok(prices)
+}
+
+//Checks what was read from a file and turns it into a menu
+fn build_menu(menu: MenuDef) -> Result<Menu, MenuError> {
+    let mut ingredients: Vec<Ingredient> = Vec::new();
+    for def in menu.ingredient {
+        if def.name.trim().is_empty() {
+            return Err(MenuError::Invalid("every ingredient needs a name".to_owned()));
+        }
+        if ingredients.iter().any(|ingredient| ingredient.get_name() == def.name) {
+            return Err(MenuError::Invalid(format!("{} is listed twice", def.name)));
+        }
+        if def.supply_cost.iter().any(|cost| *cost < 0.0) {
+            return Err(MenuError::Invalid(format!("{} has a negative supply cost", def.name)));
+        }
+        if def.shelf_life.is_some_and(|shelf_life| shelf_life.iter().any(|days| *days <= 0)) {
+            return Err(MenuError::Invalid(format!(
+                "{} must keep for at least one day",
+                def.name
+            )));
+        }
+        if def.starting_inv < 0 {
+            return Err(MenuError::Invalid(format!(
+                "{} has a negative starting inventory",
+                def.name
+            )));
+        }
+        if !(1..=3).contains(&def.quality) {
+            return Err(MenuError::Invalid(format!(
+                "{} has quality {}, it must be from 1 to 3",
+                def.name, def.quality
+            )));
+        }
+        let supply_cost = to_prices(def.supply_cost, &def.name, "a supply cost")?;
+        if supply_cost.iter().any(|cost| cost.checked_mul(def.starting_inv).is_none()) {
+            return Err(MenuError::Invalid(format!(
+                "{} has a starting inventory worth too much to hold",
+                def.name
+            )));
+        }
+        ingredients.push(Ingredient::new(
+            def.name,
+            def.quality,
+            supply_cost,
+            def.shelf_life,
+            def.starting_inv,
+        ));
+    }
+
+    if menu.item.is_empty() {
+        return Err(MenuError::Invalid("the menu has no items".to_owned()));
+    }
+
+    let mut items: Vec<MenuItem> = Vec::new();
+    for def in menu.item {
+        if def.name.trim().is_empty() || def.category.trim().is_empty() {
+            return Err(MenuError::Invalid(
+                "every item needs a name and a category".to_owned(),
+            ));
+        }
+        if items.iter().any(|item| item.get_name() == def.name) {
+            return Err(MenuError::Invalid(format!("{} is on the menu twice", def.name)));
+        }
+        if def.price < 0.0 {
+            return Err(MenuError::Invalid(format!("{} has a negative price", def.name)));
+        }
+        let reference_price = def.reference_price.unwrap_or(def.price);
+        if reference_price <= 0.0 {
+            return Err(MenuError::Invalid(format!(
+                "{} needs a reference price above zero",
+                def.name
+            )));
+        }
+        if def.recipe.is_empty() {
+            return Err(MenuError::Invalid(format!("{} has no recipe", def.name)));
+        }
+        for (ingredient, amount) in &def.recipe {
+            if !ingredients.iter().any(|known| known.get_name() == *ingredient) {
+                return Err(MenuError::Invalid(format!(
+                    "{} needs {}, which isn't an ingredient",
+                    def.name, ingredient
+                )));
+            }
+            if *amount <= 0 {
+                return Err(MenuError::Invalid(format!(
+                    "{} must use more than zero {}",
+                    def.name, ingredient
+                )));
+            }
+        }
+        let price = to_money(def.price, &def.name, "a price")?;
+        let reference_price = to_money(reference_price, &def.name, "a reference price")?;
+        if price > MAX_PRICE {
+            return Err(MenuError::Invalid(format!(
+                "{} can't be priced at more than {}",
+                def.name, MAX_PRICE
+            )));
+        }
+        items.push(MenuItem::new(
+            def.name,
+            def.category,
+            price,
+            reference_price,
+            def.recipe.into_iter().collect(),
+        ));
+    }
+
+    let mut suppliers: Vec<Supplier> = Vec::new();
+    for def in menu.supplier {
+        if def.name.trim().is_empty() {
+            return Err(MenuError::Invalid("every supplier needs a name".to_owned()));
+        }
+        if suppliers.iter().any(|supplier| supplier.get_name() == def.name) {
+            return Err(MenuError::Invalid(format!("{} is listed twice", def.name)));
+        }
+        let mut prices: Vec<(String, [Money; 3])> = Vec::new();
+        for (ingredient, dollars) in def.prices {
+            if !ingredients.iter().any(|known| known.get_name() == *ingredient) {
+                return Err(MenuError::Invalid(format!(
+                    "{} sells {}, which isn't an ingredient",
+                    def.name, ingredient
+                )));
+            }
+            if dollars.iter().any(|price| *price < 0.0) {
+                return Err(MenuError::Invalid(format!(
+                    "{} has a negative price for {}",
+                    def.name, ingredient
+                )));
+            }
+            let what = format!("a price for {}", ingredient);
+            prices.push((ingredient, to_prices(dollars, &def.name, &what)?));
+        }
+        if def.min_order < 1 || def.bulk_amount.is_some_and(|amount| amount < 1) {
+            return Err(MenuError::Invalid(format!(
+                "{} must take orders of at least one unit",
+                def.name
+            )));
+        }
+        if !(0.0..1.0).contains(&def.bulk_discount) {
+            return Err(MenuError::Invalid(format!(
+                "{} must have a bulk discount from 0 up to 1",
+                def.name
+            )));
+        }
+        if def.lead_time < 0 {
+            return Err(MenuError::Invalid(format!("{} has a negative lead time", def.name)));
+        }
+        if !(0.0..=1.0).contains(&def.reliability) {
+            return Err(MenuError::Invalid(format!(
+                "{} must have a reliability from 0 to 1",
+                def.name
+            )));
+        }
+        suppliers.push(Supplier::new(
+            def.name,
+            prices,
+            def.min_order,
+            def.bulk_amount,
+            def.bulk_discount,
+            def.lead_time,
+            def.reliability,
+        ));
+    }
+    if suppliers.is_empty() {
+        suppliers.push(Supplier::market(&ingredients));
+    }
+
+    let segments = if menu.segment.is_empty() {
+        let defaults: SegmentsDef = toml::from_str(DEFAULT_SEGMENTS).expect("default segments are valid");
+        build_segments(defaults.segment)?
+    } else {
+        build_segments(menu.segment)?
+    };
+
+    Ok(Menu {
+        items,
+        ingredients,
+        suppliers,
+        segments,
+    })
+}
+
+//Checks the customer segments read from a file
+fn build_segments(defs: Vec<SegmentDef>) -> Result<Vec<Segment>, MenuError> {
+    let mut segments: Vec<Segment> = Vec::new();
+    for def in defs {
+        if def.name.trim().is_empty() {
+            return Err(MenuError::Invalid("every segment needs a name".to_owned()));
+        }
+        if segments.iter().any(|segment| segment.get_name() == def.name) {
+            return Err(MenuError::Invalid(format!("{} is listed twice", def.name)));
+        }
+        if def.budget[0] < 0.0 || def.budget[1] < def.budget[0] {
+            return Err(MenuError::Invalid(format!(
+                "{} needs a budget from zero up, the least first",
+                def.name
+            )));
+        }
+        let budget = (
+            to_money(def.budget[0], &def.name, "a budget")?,
+            to_money(def.budget[1], &def.name, "a budget")?,
+        );
+        if def.likes.values().any(|like| *like < 0.0) {
+            return Err(MenuError::Invalid(format!("{} has a negative like", def.name)));
+        }
+        if def.quality_premium < 0.0 || def.price_sensitivity <= 0.0 {
+            return Err(MenuError::Invalid(format!(
+                "{} needs a quality premium from zero up and a price sensitivity above zero",
+                def.name
+            )));
+        }
+        if def.party[0] < 1 || def.party[1] < def.party[0] {
+            return Err(MenuError::Invalid(format!(
+                "{} must come in parties of at least one, the fewest first",
+                def.name
+            )));
+        }
+        let mut slots: Vec<(Slot, f64)> = Vec::new();
+        for (name, weight) in &def.slots {
+            let slot = Slot::parse(name).ok_or_else(|| {
+                MenuError::Invalid(format!("{} comes in during {}, which isn't a time slot", def.name, name))
+            })?;
+            if *weight < 0.0 {
+                return Err(MenuError::Invalid(format!(
+                    "{} has a negative weight for {}",
+                    def.name, name
+                )));
+            }
+            slots.push((slot, *weight));
+        }
+        slots.sort_by_key(|(slot, _)| SLOTS.iter().position(|other| other == slot));
+        if !slots.is_empty() && slots.iter().all(|(_, weight)| *weight <= 0.0) {
+            return Err(MenuError::Invalid(format!("{} never comes in", def.name)));
+        }
+        if def.days.iter().any(|weight| *weight < 0.0) {
+            return Err(MenuError::Invalid(format!("{} has a negative weight for a day", def.name)));
+        }
+        segments.push(Segment::new(
+            def.name,
+            budget,
+            def.likes.into_iter().collect(),
+            def.quality_premium,
+            def.price_sensitivity,
+            (def.party[0], def.party[1]),
+            slots,
+            def.days,
+        ));
+    }
+    for (weekday, name) in WEEKDAYS.iter().enumerate() {
+        if !segments.iter().any(|segment| segment.get_day_weight(weekday as i64) > 0.0) {
+            return Err(MenuError::Invalid(format!("no customers come in on {}", name)));
+        }
+    }
+    Ok(segments)
+}
+
+#[test]
+fn default_menu_test() {
+    let menu = default_menu();
+    let names: Vec<String> = menu.items.iter().map(|item| item.get_name()).collect();
+    assert_eq!(vec!["Burger", "Fries", "Soda"], names);
+    assert_eq!(5, menu.ingredients.len());
+    assert_eq!(2, menu.items[1].get_amount("Potato"));
+    assert_eq!(Money::from_dollars(2), menu.ingredients[1].get_supply_cost());
+    //Cups never spoil
+    assert_eq!(None, menu.ingredients[4].get_shelf_life());
+    assert_eq!("Corner Market", menu.suppliers[0].get_name());
+    let segments: Vec<String> = menu.segments.iter().map(|segment| segment.get_name()).collect();
+    assert_eq!(vec!["Students", "Families", "Office workers", "Foodies"], segments);
+    assert_eq!((2, 5), menu.segments[1].get_party());
+}
+
+#[test]
+fn parse_json_test() {
+    let menu = parse_json(
+        r#"{ "ingredient": [ { "name": "Lettuce", "supply_cost": [1.0, 2.0, 3.0],
+                              "starting_inv": 20, "quality": 2 } ],
+             "item": [ { "name": "Salad", "category": "main", "price": 6.0,
+                         "recipe": { "Lettuce": 2 } } ] }"#,
+    )
+    .unwrap();
+    assert_eq!("Salad", menu.items[0].get_name());
+    assert_eq!(2, menu.items[0].get_amount("Lettuce"));
+    assert_eq!(Money::from_dollars(6), menu.items[0].get_reference_price());
+    assert_eq!(2, menu.ingredients[0].get_quality());
+    assert_eq!(Money::from_dollars(2), menu.ingredients[0].get_supply_cost());
+    //Without suppliers the market sells at the supply cost
+    assert_eq!("Market", menu.suppliers[0].get_name());
+    assert_eq!(Some(Money::from_dollars(3)), menu.suppliers[0].get_price("Lettuce", 3));
+}
+
+#[test]
+fn invalid_menu_test() {
+    assert!(parse_toml("").is_err());
+    assert!(parse_toml("ingredient = []\nitem = []").is_err());
+    let unknown = r#"
+        [[ingredient]]
+        name = "Flour"
+        supply_cost = [1.0, 1.5, 2.0]
+        starting_inv = 10
+
+        [[item]]
+        name = "Pie"
+        category = "dessert"
+        price = 3.0
+        recipe = { Flour = 1, Apple = 2 }
+    "#;
+    match parse_toml(unknown) {
+        Err(MenuError::Invalid(_)) => (),
+        _ => panic!("recipes with unknown ingredients should be rejected"),
+    }
+
+    let twice = r#"
+        [[ingredient]]
+        name = "Flour"
+        supply_cost = [1.0, 1.5, 2.0]
+        starting_inv = 10
+
+        [[ingredient]]
+        name = "Flour"
+        supply_cost = [1.0, 1.5, 2.0]
+        starting_inv = 10
+
+        [[item]]
+        name = "Pie"
+        category = "dessert"
+        price = 3.0
+        recipe = { Flour = 1 }
+    "#;
+    match parse_toml(twice) {
+        Err(MenuError::Invalid(_)) => (),
+        _ => panic!("duplicate ingredients should be rejected"),
+    }
+
+    let weekends = r#"
+        [[ingredient]]
+        name = "Flour"
+        supply_cost = [1.0, 1.5, 2.0]
+        starting_inv = 10
+
+        [[item]]
+        name = "Pie"
+        category = "dessert"
+        price = 3.0
+        recipe = { Flour = 1 }
+
+        [[segment]]
+        name = "Tourists"
+        budget = [10.0, 30.0]
+        quality_premium = 0.3
+        price_sensitivity = 4.0
+        slots = { Brunch = 1.0 }
+    "#;
+    match parse_toml(weekends) {
+        Err(MenuError::Invalid(_)) => (),
+        _ => panic!("segments with unknown slots should be rejected"),
+    }
+    match parse_toml(&weekends.replace("Brunch", "Lunch").replace("price_sensitivity = 4.0",
+        "price_sensitivity = 4.0\n        days = [0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0]")) {
+        Err(MenuError::Invalid(e)) => assert_eq!("no customers come in on Monday", e),
+        _ => panic!("every day needs customers"),
+    }
+    assert_eq!(1, parse_toml(&weekends.replace("Brunch", "Lunch")).unwrap().segments.len());
+    match parse_toml(&weekends.replace("Brunch", "Lunch").replace("30.0", "1e30")) {
+        Err(MenuError::Invalid(e)) => assert_eq!("Tourists has a budget too large to hold", e),
+        _ => panic!("budgets too large to hold should be rejected"),
+    }
+    match parse_toml(&weekends.replace("Brunch", "Lunch").replace("price = 3.0", "price = 1e300")) {
+        Err(MenuError::Invalid(e)) => assert_eq!("Pie has a price too large to hold", e),
+        _ => panic!("prices too large to hold should be rejected"),
+    }
+    match parse_toml(&weekends.replace("Brunch", "Lunch").replace("price = 3.0", "price = 2000000.0")) {
+        Err(MenuError::Invalid(e)) => assert_eq!("Pie can't be priced at more than $1000000.00", e),
+        _ => panic!("prices over the most an item can cost should be rejected"),
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following code is used to represent a menu item at the restaurant.
+// A menu item has a name, category, price, reference price and a recipe.
+// The reference price is what customers expect to pay for the item, see demand.
+// The recipe lists how much of each ingredient goes into one of the item,
+// so the restaurant's ingredient stock decides how many can be made.
+// Menu items are normally read from a menu file, see menu_file.
+// A menu item doesn't have many functions, it just manages its own data.
+
+use crate::simulator::Money;
+use serde::{Deserialize, Serialize};
+
+// The most an item can be priced at, $1,000,000
+// Small enough that the price with tax, times everyone in a party, always fits in Money
+pub const MAX_PRICE: Money = Money::from_cents(100_000_000);
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MenuItem {
+    name: String,
+    //Customers pick items by category, e.g. main, side or drink
+    category: String,
+    price: Money,
+    //What customers expect to pay for the item at quality 1
+    reference_price: Money,
+    //Ingredient names and the amount of each used to make one item
+    recipe: Vec<(String, i64)>,
+}
+
+impl MenuItem {
+    pub fn new(
+        name: String,
+        category: String,
+        price: Money,
+        reference_price: Money,
+        recipe: Vec<(String, i64)>,
+    ) -> MenuItem {
+        MenuItem {
+            name,
+            category,
+            price,
+            reference_price,
+            recipe,
+        }
+    }
+
+    //Getters
+    pub fn get_name(&self) -> String {
+        self.name.to_owned()
+    }
+
+    pub fn get_category(&self) -> String {
+        self.category.to_owned()
+    }
+
+    pub fn get_price(&self) -> Money {
+        self.price
+    }
+
+    pub fn get_reference_price(&self) -> Money {
+        self.reference_price
+    }
+
+    pub fn get_recipe(&self) -> &[(String, i64)] {
+        &self.recipe
+    }
+
+    //Amount of the given ingredient used to make one item
+    pub fn get_amount(&self, ingredient: &str) -> i64 {
+        self.recipe
+            .iter()
+            .find(|(name, _)| name == ingredient)
+            .map_or(0, |(_, amount)| *amount)
+    }
+
+    //Setters
+    pub fn set_price(&mut self, new_price: Money) {
+        self.price = new_price;
+    }
+}
+
+#[test]
+fn set_price_test() {
+    let price = Money::from_cents(999);
+    let mut menu_test = MenuItem::new("Test".to_owned(), "main".to_owned(), price, price, vec![("Bun".to_owned(), 1)]);
+    menu_test.set_price(Money::from_cents(450));
+    assert_eq!(Money::from_cents(450), menu_test.get_price());
+}
+
+#[test]
+fn recipe_test() {
+    let menu_test = MenuItem::new(
+        "Test".to_owned(),
+        "main".to_owned(),
+        Money::from_cents(999),
+        Money::from_cents(999),
+        vec![("Bun".to_owned(), 1), ("Patty".to_owned(), 2)],
+    );
+    assert_eq!(2, menu_test.get_amount("Patty"));
+    assert_eq!(0, menu_test.get_amount("Cup"));
+}
@@ -0,0 +1,80 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following are the fixed costs of running the restaurant and the taxes it pays.
+// They are paid whether or not anyone comes in:
+//     rent is paid at the end of every Sunday for the week,
+//     utilities are paid every day, more the more customers were served,
+//     insurance is paid on the first day of every month.
+// Customers pay sales tax on top of the menu price of everything they buy.
+// The restaurant only holds the tax for the government,
+// so it isn't revenue and is paid over at the end of every Sunday.
+// Income tax is a share of the month's profit, paid on the last day of the month.
+// A month that lost money pays no income tax.
+// All of these are paid in cash and written down in the ledger.
+
+use super::ledger::MONTH_DAYS;
+use super::schedule;
+use crate::simulator::Money;
+
+pub const RENT: Money = Money::from_cents(30_000);
+// Utilities cost this much a day, and this much more for every customer served
+pub const UTILITIES_BASE: Money = Money::from_cents(1_500);
+pub const UTILITIES_PER_CUSTOMER: Money = Money::from_cents(10);
+pub const INSURANCE: Money = Money::from_cents(10_000);
+// Share of the menu price customers pay in sales tax
+pub const SALES_TAX_RATE: f64 = 0.08;
+// Share of a month's profit paid in income tax
+pub const INCOME_TAX_RATE: f64 = 0.2;
+
+//Sales tax on an item sold at the given price
+pub fn calc_sales_tax(price: Money) -> Money {
+    price.mul_f64(SALES_TAX_RATE)
+}
+
+//What a customer pays for an item at the given price, with the sales tax on top
+//None if that is too large to hold
+pub fn calc_total(price: Money) -> Option<Money> {
+    price.checked_add(calc_sales_tax(price))
+}
+
+//Income tax on the given profit, nothing on a loss
+pub fn calc_income_tax(profit: Money) -> Money {
+    profit.max(Money::ZERO).mul_f64(INCOME_TAX_RATE)
+}
+
+//Utilities for a day the given number of customers were served
+pub fn calc_utilities(customers_served: i64) -> Money {
+    UTILITIES_BASE + UTILITIES_PER_CUSTOMER * customers_served.max(0)
+}
+
+//Whether rent is paid and sales tax is paid over on the given day, the last day of every week
+pub fn is_week_end(day: i64) -> bool {
+    schedule::get_weekday(day) == 6
+}
+
+//Whether insurance is paid on the given day, the first day of every month
+pub fn is_month_start(day: i64) -> bool {
+    day >= 1 && (day - 1) % MONTH_DAYS == 0
+}
+
+//Whether income tax is paid on the given day, the last day of every month
+pub fn is_month_end(day: i64) -> bool {
+    day >= 1 && day % MONTH_DAYS == 0
+}
+
+#[test]
+fn overhead_test() {
+    assert_eq!(Money::from_cents(40), calc_sales_tax(Money::from_dollars(5)));
+    assert_eq!(Some(Money::from_cents(540)), calc_total(Money::from_dollars(5)));
+    assert_eq!(None, calc_total(Money::from_cents(i64::MAX)));
+    assert_eq!(Money::from_dollars(20), calc_income_tax(Money::from_dollars(100)));
+    assert_eq!(Money::ZERO, calc_income_tax(Money::from_dollars(-100)));
+    assert_eq!(UTILITIES_BASE, calc_utilities(0));
+    assert_eq!(UTILITIES_BASE + Money::from_dollars(5), calc_utilities(50));
+    assert!(is_week_end(7) && !is_week_end(8));
+    assert!(is_month_start(1) && is_month_start(29) && !is_month_start(28));
+    assert!(is_month_end(28) && !is_month_end(29));
+}
@@ -0,0 +1,237 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following keeps track of what customers think of the restaurant.
+// Every party that is served leaves a review once they are done ordering.
+// How satisfied they are depends on how long they waited compared to their patience,
+// the quality of what they got, how the prices compared to what they expected to pay
+// and whether what they wanted was sold out or too expensive.
+// Satisfaction turns into one to five stars and a line of text about what stood out.
+// The restaurant keeps the most recent MAX_REVIEWS reviews, and its star rating
+// is the average of them. A restaurant nobody has reviewed yet has NEUTRAL_RATING stars.
+// The better the rating, the more people come in, see reputation_mod.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+// Number of reviews kept, older ones are dropped
+pub const MAX_REVIEWS: usize = 50;
+// Star rating of a restaurant without reviews
+pub const NEUTRAL_RATING: f64 = 3.0;
+// How many more or fewer people come in at five or one stars, as a fraction
+const REPUTATION_EFFECT: f64 = 0.3;
+// How much each part of a visit counts toward satisfaction, they add up to 1
+const WAIT_WEIGHT: f64 = 0.3;
+const QUALITY_WEIGHT: f64 = 0.25;
+const PRICE_WEIGHT: f64 = 0.25;
+const STOCK_WEIGHT: f64 = 0.2;
+
+// The parts of a visit a customer can be happy or unhappy with
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Factor {
+    Wait,
+    Quality,
+    Price,
+    Stock,
+}
+
+// How one party's visit went, used to work out how satisfied they were
+pub struct Visit {
+    //Minutes they waited to be seated and would have waited at most
+    pub wait: i64,
+    pub patience: i64,
+    //Average quality of the items they got, 0 if they got nothing
+    pub quality: f64,
+    //Average of what they expected to pay over what they paid, for the items they got
+    //Above 1 is a bargain, 0 if they got nothing
+    pub value: f64,
+    //Items ordered, and how many of those were sold out or too expensive
+    pub ordered: i64,
+    pub sold_out: i64,
+    pub too_expensive: i64,
+}
+
+impl Visit {
+    //How happy the customer is with every part of the visit, from 0 to 1
+    pub fn get_scores(&self) -> [(Factor, f64); 4] {
+        let ordered = self.ordered.max(1) as f64;
+        let wait = if self.patience > 0 {
+            1.0 - self.wait as f64 / self.patience as f64
+        } else {
+            1.0
+        };
+        let quality = self.quality / 3.0;
+        let price = ((self.value - 0.6) / 0.8) * (1.0 - self.too_expensive as f64 / ordered);
+        let stock = 1.0 - self.sold_out as f64 / ordered;
+        [
+            (Factor::Wait, wait.clamp(0.0, 1.0)),
+            (Factor::Quality, quality.clamp(0.0, 1.0)),
+            (Factor::Price, price.clamp(0.0, 1.0)),
+            (Factor::Stock, stock.clamp(0.0, 1.0)),
+        ]
+    }
+
+    //How satisfied the customer is overall, from 0 to 1
+    pub fn calc_satisfaction(&self) -> f64 {
+        self.get_scores()
+            .iter()
+            .map(|(factor, score)| {
+                let weight = match factor {
+                    Factor::Wait => WAIT_WEIGHT,
+                    Factor::Quality => QUALITY_WEIGHT,
+                    Factor::Price => PRICE_WEIGHT,
+                    Factor::Stock => STOCK_WEIGHT,
+                };
+                weight * score
+            })
+            .sum()
+    }
+
+    //Stars from 1 to 5 for the visit
+    pub fn calc_stars(&self) -> i64 {
+        (1.0 + 4.0 * self.calc_satisfaction()).round() as i64
+    }
+
+    //Writes the text of a review
+    //Happy customers write about the best part of their visit, unhappy ones about the worst
+    pub fn write_review<R: Rng>(&self, rng: &mut R) -> String {
+        let stars = self.calc_stars();
+        let scores = self.get_scores();
+        let pick = |best: bool| {
+            scores
+                .iter()
+                .fold(None, |found: Option<(Factor, f64)>, (factor, score)| match found {
+                    Some((_, other)) if (best && other >= *score) || (!best && other <= *score) => found,
+                    _ => Some((*factor, *score)),
+                })
+                .map_or(Factor::Wait, |(factor, _)| factor)
+        };
+        let openings: &[&str] = match stars {
+            5 => &["Loved it!", "Best meal in town!", "Five stars, no question."],
+            4 => &["Pretty good.", "Would come back.", "Solid spot."],
+            3 => &["It was okay.", "Nothing special.", "Fine, I guess."],
+            2 => &["Not great.", "Disappointing.", "Expected better."],
+            _ => &["Never again.", "Awful.", "Stay away."],
+        };
+        let details: &[&str] = if stars >= 4 {
+            match pick(true) {
+                Factor::Wait => &["We were seated right away.", "No wait at all."],
+                Factor::Quality => &["The food was excellent.", "You can taste the quality."],
+                Factor::Price => &["Great value for the money.", "Cheap and filling."],
+                Factor::Stock => &["They had everything we wanted.", "Nothing was sold out."],
+            }
+        } else {
+            match pick(false) {
+                Factor::Wait => &["We waited forever for a table.", "The line barely moved."],
+                Factor::Quality => &["The food was bland.", "Tasted like it came from a box."],
+                Factor::Price => &["Way too pricey for what you get.", "The prices are a rip off."],
+                Factor::Stock => &["Half the menu was sold out.", "They ran out of what we came for."],
+            }
+        };
+        format!(
+            "{} {}",
+            openings[rng.gen_range(0, openings.len())],
+            details[rng.gen_range(0, details.len())]
+        )
+    }
+}
+
+// What one party thought of the restaurant
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Review {
+    pub day: i64,
+    //Segment the party belongs to
+    pub segment: String,
+    pub stars: i64,
+    pub text: String,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Reputation {
+    //Most recent reviews, oldest first
+    reviews: Vec<Review>,
+}
+
+impl Reputation {
+    pub fn new() -> Reputation {
+        Reputation { reviews: Vec::new() }
+    }
+
+    //Getters
+    pub fn get_reviews(&self) -> &[Review] {
+        &self.reviews
+    }
+
+    //Average stars of the reviews kept, NEUTRAL_RATING if there are none
+    pub fn get_rating(&self) -> f64 {
+        if self.reviews.is_empty() {
+            return NEUTRAL_RATING;
+        }
+        self.reviews.iter().map(|review| review.stars).sum::<i64>() as f64 / self.reviews.len() as f64
+    }
+
+    //How many more or fewer people come in because of the rating, 1 is as many as usual
+    pub fn reputation_mod(&self) -> f64 {
+        1.0 + REPUTATION_EFFECT * (self.get_rating() - NEUTRAL_RATING) / 2.0
+    }
+
+    //Adds a review, dropping the oldest once there are too many
+    pub fn add_review(&mut self, review: Review) {
+        self.reviews.push(review);
+        if self.reviews.len() > MAX_REVIEWS {
+            let extra = self.reviews.len() - MAX_REVIEWS;
+            self.reviews.drain(..extra);
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::simulator::GameRng;
+
+#[cfg(test)]
+fn test_visit() -> Visit {
+    Visit {
+        wait: 0,
+        patience: 30,
+        quality: 3.0,
+        value: 1.4,
+        ordered: 2,
+        sold_out: 0,
+        too_expensive: 0,
+    }
+}
+
+#[test]
+fn stars_test() {
+    let mut visit = test_visit();
+    assert_eq!(5, visit.calc_stars());
+    assert!(visit.write_review(&mut GameRng::new(1)).len() > 10);
+    //A long wait for bad, overpriced food and then the rest was sold out
+    visit.wait = 30;
+    visit.quality = 1.0;
+    visit.value = 0.6;
+    visit.sold_out = 2;
+    assert_eq!(1, visit.calc_stars());
+}
+
+#[test]
+fn rating_test() {
+    let mut reputation = Reputation::new();
+    assert_eq!(NEUTRAL_RATING, reputation.get_rating());
+    assert_eq!(1.0, reputation.reputation_mod());
+    for day in 0..MAX_REVIEWS as i64 {
+        let review = Review { day, segment: "Foodies".to_owned(), stars: 1, text: String::new() };
+        reputation.add_review(review);
+    }
+    assert!((reputation.reputation_mod() - 0.7).abs() < 1e-9);
+    //Old reviews drop off as new ones come in
+    for day in 0..MAX_REVIEWS as i64 {
+        let review = Review { day, segment: "Foodies".to_owned(), stars: 5, text: String::new() };
+        reputation.add_review(review);
+    }
+    assert_eq!(MAX_REVIEWS, reputation.get_reviews().len());
+    assert_eq!(5.0, reputation.get_rating());
+    assert!((reputation.reputation_mod() - 1.3).abs() < 1e-9);
+}
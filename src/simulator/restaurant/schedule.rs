@@ -0,0 +1,228 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following is used to schedule employees through the week.
+// The restaurant is open every day from 7am to 11pm, and the day is split into four shifts:
+// morning, lunch, dinner and close, each SHIFT_HOURS long.
+// Every employee has a weekly schedule of the shifts they work
+// and can't be scheduled for more than their own maximum hours a week.
+// Customers come in during time slots that don't line up with the shifts:
+// breakfast, lunch, afternoon, dinner and late night.
+// Every slot has its own share of the day's customers, so lunch and dinner are a rush
+// while the afternoon is quiet, and only the employees working during a slot,
+// for the hours they are there, help serve the customers that come in during it.
+// Employees are paid for the hours they work, and every hour past
+// OVERTIME_HOURS in a week is paid at OVERTIME_RATE times their wage.
+// Day 1 of the game is a Monday.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+pub const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+pub const SHIFT_HOURS: i64 = 4;
+pub const OVERTIME_HOURS: i64 = 40;
+pub const OVERTIME_RATE: f64 = 1.5;
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Shift {
+    Morning,
+    Lunch,
+    Dinner,
+    Close,
+}
+
+// Every shift in the order they happen
+pub const SHIFTS: [Shift; 4] = [Shift::Morning, Shift::Lunch, Shift::Dinner, Shift::Close];
+
+impl Shift {
+    pub fn get_name(self) -> String {
+        match self {
+            Shift::Morning => "Morning".to_owned(),
+            Shift::Lunch => "Lunch".to_owned(),
+            Shift::Dinner => "Dinner".to_owned(),
+            Shift::Close => "Close".to_owned(),
+        }
+    }
+
+    //Hour of the day the shift starts, on a 24 hour clock
+    pub fn get_start(self) -> i64 {
+        match self {
+            Shift::Morning => 7,
+            Shift::Lunch => 11,
+            Shift::Dinner => 15,
+            Shift::Close => 19,
+        }
+    }
+
+    //Reads a shift name, any case
+    pub fn parse(name: &str) -> Option<Shift> {
+        SHIFTS
+            .iter()
+            .find(|shift| shift.get_name().eq_ignore_ascii_case(name))
+            .copied()
+    }
+}
+
+// The parts of the day customers come in during
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Slot {
+    Breakfast,
+    Lunch,
+    Afternoon,
+    Dinner,
+    LateNight,
+}
+
+// Every time slot in the order they happen
+pub const SLOTS: [Slot; 5] = [
+    Slot::Breakfast,
+    Slot::Lunch,
+    Slot::Afternoon,
+    Slot::Dinner,
+    Slot::LateNight,
+];
+
+impl Slot {
+    pub fn get_name(self) -> String {
+        match self {
+            Slot::Breakfast => "Breakfast".to_owned(),
+            Slot::Lunch => "Lunch".to_owned(),
+            Slot::Afternoon => "Afternoon".to_owned(),
+            Slot::Dinner => "Dinner".to_owned(),
+            Slot::LateNight => "Late night".to_owned(),
+        }
+    }
+
+    //Hours of the day the slot starts and ends, on a 24 hour clock
+    //The slots run one after the other, covering every hour the restaurant is open
+    pub fn get_hours(self) -> (i64, i64) {
+        match self {
+            Slot::Breakfast => (7, 11),
+            Slot::Lunch => (11, 14),
+            Slot::Afternoon => (14, 17),
+            Slot::Dinner => (17, 20),
+            Slot::LateNight => (20, 23),
+        }
+    }
+
+    pub fn get_length(self) -> i64 {
+        let (start, end) = self.get_hours();
+        end - start
+    }
+
+    //Fraction of the day's customers that come in during the slot
+    pub fn get_share(self) -> f64 {
+        match self {
+            Slot::Breakfast => 0.15,
+            Slot::Lunch => 0.35,
+            Slot::Afternoon => 0.1,
+            Slot::Dinner => 0.3,
+            Slot::LateNight => 0.1,
+        }
+    }
+
+    //Hours of the given shift that fall in the slot
+    pub fn get_overlap(self, shift: Shift) -> i64 {
+        let (start, end) = self.get_hours();
+        let shift_end = shift.get_start() + SHIFT_HOURS;
+        (end.min(shift_end) - start.max(shift.get_start())).max(0)
+    }
+
+    //Reads a slot name, any case and with or without spaces
+    pub fn parse(name: &str) -> Option<Slot> {
+        let name = name.replace(' ', "");
+        SLOTS
+            .iter()
+            .find(|slot| slot.get_name().replace(' ', "").eq_ignore_ascii_case(&name))
+            .copied()
+    }
+
+    //Picks the slot a customer comes in during, following the share of every slot
+    pub fn pick<R: Rng>(rng: &mut R) -> Slot {
+        let mut roll = rng.gen_range(0.0, 1.0);
+        for slot in SLOTS.iter() {
+            if roll < slot.get_share() {
+                return *slot;
+            }
+            roll -= slot.get_share();
+        }
+        Slot::LateNight
+    }
+}
+
+//Day of the week of the given game day, 0 is Monday
+pub fn get_weekday(day: i64) -> i64 {
+    (day - 1).rem_euclid(7)
+}
+
+//Reads a day of the week, either the full name or the first three letters, any case
+pub fn parse_weekday(name: &str) -> Option<i64> {
+    WEEKDAYS
+        .iter()
+        .position(|weekday| {
+            weekday.eq_ignore_ascii_case(name)
+                || (name.len() == 3 && weekday[..3].eq_ignore_ascii_case(name))
+        })
+        .map(|weekday| weekday as i64)
+}
+
+//Lunch and dinner on as many days as fit in the given hours, starting Monday
+//Used to give new employees a schedule
+pub fn fill_schedule(max_hours: i64) -> Vec<(i64, Shift)> {
+    let mut shifts: Vec<(i64, Shift)> = Vec::new();
+    for weekday in 0..7 {
+        for shift in &[Shift::Lunch, Shift::Dinner] {
+            if (shifts.len() as i64 + 1) * SHIFT_HOURS <= max_hours {
+                shifts.push((weekday, *shift));
+            }
+        }
+    }
+    shifts
+}
+
+#[test]
+fn weekday_test() {
+    assert_eq!(0, get_weekday(1));
+    assert_eq!(6, get_weekday(7));
+    assert_eq!(0, get_weekday(8));
+    assert_eq!(Some(2), parse_weekday("wed"));
+    assert_eq!(Some(6), parse_weekday("Sunday"));
+    assert_eq!(None, parse_weekday("someday"));
+    assert_eq!(Some(Shift::Close), Shift::parse("close"));
+    assert_eq!(Some(Slot::LateNight), Slot::parse("LateNight"));
+    assert_eq!(Some(Slot::LateNight), Slot::parse("late night"));
+}
+
+#[test]
+fn slot_test() {
+    assert_eq!(4, Slot::Breakfast.get_overlap(Shift::Morning));
+    assert_eq!(0, Slot::Breakfast.get_overlap(Shift::Lunch));
+    //The afternoon is the end of lunch and the start of dinner
+    assert_eq!(1, Slot::Afternoon.get_overlap(Shift::Lunch));
+    assert_eq!(2, Slot::Afternoon.get_overlap(Shift::Dinner));
+    //Every hour of every shift falls in a slot
+    for shift in SHIFTS.iter() {
+        let covered: i64 = SLOTS.iter().map(|slot| slot.get_overlap(*shift)).sum();
+        assert_eq!(SHIFT_HOURS, covered);
+    }
+    let shares: f64 = SLOTS.iter().map(|slot| slot.get_share()).sum();
+    assert!((shares - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn fill_schedule_test() {
+    assert_eq!(14, fill_schedule(56).len());
+    let part_time = fill_schedule(20);
+    assert_eq!(5, part_time.len());
+    assert_eq!((2, Shift::Lunch), part_time[4]);
+}
@@ -0,0 +1,216 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following describes the kinds of customers that come to the restaurant.
+// Every customer belongs to a segment, such as students, families, office workers or foodies.
+// A segment decides how much each person spends, which items and categories they like,
+// how much more they pay for better food, how quickly high prices put them off,
+// how many people come in together and what time of day they come in.
+// How many of each segment come in changes with the day of the week,
+// so office workers fill the week while families come in on the weekend.
+// Segments are listed in the menu file, see menu_file,
+// and a menu without any gets the ones in default_segments.toml.
+
+use super::demand;
+use super::schedule::{Slot, SLOTS};
+use crate::simulator::Money;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Segment {
+    name: String,
+    //Least and most each person in a party brings to spend
+    budget: (Money, Money),
+    //Menu items or categories ordered more or less often than the rest, 1 is average
+    likes: Vec<(String, f64)>,
+    //How much more they pay for every level of quality above 1, as a fraction
+    quality_premium: f64,
+    //How fast their chance of buying falls as the price goes past what they will pay
+    price_sensitivity: f64,
+    //Fewest and most people that come in together
+    party: (i64, i64),
+    //How many come in during every slot compared to the other slots, in slot order
+    slots: Vec<(Slot, f64)>,
+    //How many come in on every day of the week compared to the other segments, from Monday
+    days: [f64; 7],
+}
+
+impl Segment {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: String,
+        budget: (Money, Money),
+        likes: Vec<(String, f64)>,
+        quality_premium: f64,
+        price_sensitivity: f64,
+        party: (i64, i64),
+        slots: Vec<(Slot, f64)>,
+        days: [f64; 7],
+    ) -> Segment {
+        Segment {
+            name,
+            budget,
+            likes,
+            quality_premium,
+            price_sensitivity,
+            party,
+            slots,
+            days,
+        }
+    }
+
+    //A customer that comes in alone at the usual times, likes everything the same
+    //and cares about quality and price as much as the average customer
+    //Used to show the player what a price means to customers in general
+    pub fn average() -> Segment {
+        Segment::new(
+            "Average".to_owned(),
+            (Money::from_dollars(10), Money::from_dollars(100)),
+            Vec::new(),
+            demand::QUALITY_PREMIUM,
+            demand::PRICE_SENSITIVITY,
+            (1, 1),
+            SLOTS.iter().map(|slot| (*slot, slot.get_share())).collect(),
+            [1.0; 7],
+        )
+    }
+
+    //Getters
+    pub fn get_name(&self) -> String {
+        self.name.to_owned()
+    }
+
+    pub fn get_budget(&self) -> (Money, Money) {
+        self.budget
+    }
+
+    pub fn get_quality_premium(&self) -> f64 {
+        self.quality_premium
+    }
+
+    pub fn get_price_sensitivity(&self) -> f64 {
+        self.price_sensitivity
+    }
+
+    pub fn get_party(&self) -> (i64, i64) {
+        self.party
+    }
+
+    //How many come in on the given day of the week compared to the other segments
+    pub fn get_day_weight(&self, weekday: i64) -> f64 {
+        self.days[weekday.rem_euclid(7) as usize]
+    }
+
+    //The slot most of the segment comes in during
+    pub fn get_busiest_slot(&self) -> Slot {
+        self.slots
+            .iter()
+            .fold(None, |best: Option<(Slot, f64)>, (slot, weight)| match best {
+                Some((_, most)) if most >= *weight => best,
+                _ => Some((*slot, *weight)),
+            })
+            .map_or(Slot::Lunch, |(slot, _)| slot)
+    }
+
+    //How much more or less often they order the given item, from its name and its category
+    pub fn get_like(&self, name: &str, category: &str) -> f64 {
+        self.likes
+            .iter()
+            .filter(|(liked, _)| liked == name || liked == category)
+            .map(|(_, like)| *like)
+            .product()
+    }
+
+    //Chance a customer of the segment with the given taste buys an item
+    //at the given price, reference price and quality, see demand
+    pub fn calc_purchase_chance(&self, price: Money, reference_price: Money, quality: f64, taste: f64) -> f64 {
+        let wtp = demand::willingness_to_pay(reference_price.to_f64(), quality, taste, self.quality_premium);
+        demand::purchase_chance(price.to_f64(), wtp, self.price_sensitivity)
+    }
+
+    //Picks how many people come in together
+    pub fn pick_party<R: Rng>(&self, rng: &mut R) -> i64 {
+        rng.gen_range(self.party.0, self.party.1 + 1)
+    }
+
+    //Picks how much one person brings to spend, to the cent
+    pub fn pick_budget<R: Rng>(&self, rng: &mut R) -> Money {
+        if self.budget.1 > self.budget.0 {
+            Money::from_cents(rng.gen_range(self.budget.0.get_cents(), self.budget.1.get_cents()))
+        } else {
+            self.budget.0
+        }
+    }
+
+    //Picks the slot a party comes in during
+    pub fn pick_slot<R: Rng>(&self, rng: &mut R) -> Slot {
+        let weights: Vec<f64> = self.slots.iter().map(|(_, weight)| *weight).collect();
+        match pick_weighted(&weights, rng) {
+            Some(i) => self.slots[i].0,
+            None => Slot::pick(rng),
+        }
+    }
+}
+
+//Picks the segment of the next party to come in on the given day of the week
+//Returns None if no segment comes in that day
+pub fn pick<'a, R: Rng>(segments: &'a [Segment], weekday: i64, rng: &mut R) -> Option<&'a Segment> {
+    let weights: Vec<f64> = segments.iter().map(|segment| segment.get_day_weight(weekday)).collect();
+    pick_weighted(&weights, rng).map(|i| &segments[i])
+}
+
+//Picks a position at random, each as likely as its weight
+//Returns None if every weight is 0
+pub fn pick_weighted<R: Rng>(weights: &[f64], rng: &mut R) -> Option<usize> {
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut roll = rng.gen_range(0.0, total);
+    for (i, weight) in weights.iter().enumerate() {
+        if roll < *weight {
+            return Some(i);
+        }
+        roll -= weight;
+    }
+    weights.iter().rposition(|weight| *weight > 0.0)
+}
+
+#[cfg(test)]
+use crate::simulator::GameRng;
+
+#[test]
+fn like_test() {
+    let segment = Segment::new(
+        "Kids".to_owned(),
+        (Money::from_dollars(5), Money::from_dollars(10)),
+        vec![("side".to_owned(), 2.0), ("Fries".to_owned(), 1.5), ("drink".to_owned(), 0.0)],
+        0.0,
+        8.0,
+        (1, 1),
+        Vec::new(),
+        [1.0; 7],
+    );
+    assert_eq!(3.0, segment.get_like("Fries", "side"));
+    assert_eq!(2.0, segment.get_like("Salad", "side"));
+    assert_eq!(0.0, segment.get_like("Soda", "drink"));
+    assert_eq!(1.0, segment.get_like("Burger", "main"));
+}
+
+#[test]
+fn pick_test() {
+    let mut weekend = Segment::average();
+    weekend.days = [0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0];
+    weekend.slots = vec![(Slot::Dinner, 1.0)];
+    let segments = vec![Segment::average(), weekend];
+    let mut rng = GameRng::new(1);
+    for _ in 0..20 {
+        assert_eq!("Average", pick(&segments, 0, &mut rng).unwrap().get_name());
+        assert_eq!(Slot::Dinner, segments[1].pick_slot(&mut rng));
+    }
+    assert!(pick(&segments[1..], 2, &mut rng).is_none());
+    assert_eq!(Slot::Lunch, Segment::average().get_busiest_slot());
+}
@@ -0,0 +1,228 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following code is used to represent a supplier the restaurant buys ingredients from.
+// Every supplier has its own price list with a price per quality level for each ingredient it sells,
+// a minimum order size, an optional bulk discount and a lead time in days.
+// Orders are paid for when they are placed and arrive at the start of the day they are due.
+// A supplier with a lead time of 0 hands the order over right away.
+// Reliability is the chance an order arrives complete and on time,
+// otherwise only part of it arrives or it shows up late.
+
+use super::ingredient::Ingredient;
+use crate::simulator::Money;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Supplier {
+    name: String,
+    //Ingredient names and the price of one unit at quality 1, 2 and 3
+    prices: Vec<(String, [Money; 3])>,
+    //Fewest units that can be ordered at once
+    min_order: i64,
+    //Orders of at least this many units get the bulk discount
+    bulk_amount: Option<i64>,
+    //Fraction taken off the price of a bulk order
+    bulk_discount: f64,
+    //Days between placing an order and it arriving
+    lead_time: i64,
+    //Chance from 0 to 1 that an order arrives complete and on time
+    reliability: f64,
+}
+
+// An order that has been paid for but hasn't arrived yet
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PendingOrder {
+    pub supplier: String,
+    pub ingredient: String,
+    pub quantity: i64,
+    pub quality: i64,
+    //What one unit cost after any discount
+    pub unit_cost: Money,
+    //Day the order arrives at the start of
+    pub arrives_on: i64,
+}
+
+// What happened to an order that was due
+pub enum Delivery {
+    Arrived(PendingOrder),
+    //Only part of the order came, the rest was refunded
+    Partial { order: PendingOrder, delivered: i64, refund: Money },
+    //The order is coming on a later day
+    Late(PendingOrder),
+}
+
+impl Supplier {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: String,
+        prices: Vec<(String, [Money; 3])>,
+        min_order: i64,
+        bulk_amount: Option<i64>,
+        bulk_discount: f64,
+        lead_time: i64,
+        reliability: f64,
+    ) -> Supplier {
+        Supplier {
+            name,
+            prices,
+            min_order,
+            bulk_amount,
+            bulk_discount,
+            lead_time,
+            reliability,
+        }
+    }
+
+    //A supplier that sells every ingredient at its supply cost, right away and always in full
+    //Used when a menu doesn't list any suppliers
+    pub fn market(ingredients: &[Ingredient]) -> Supplier {
+        let prices = ingredients
+            .iter()
+            .map(|ingredient| (ingredient.get_name(), ingredient.get_supply_costs()))
+            .collect();
+        Supplier::new("Market".to_owned(), prices, 1, None, 0.0, 0, 1.0)
+    }
+
+    //Getters
+    pub fn get_name(&self) -> String {
+        self.name.to_owned()
+    }
+
+    pub fn get_prices(&self) -> &[(String, [Money; 3])] {
+        &self.prices
+    }
+
+    pub fn get_min_order(&self) -> i64 {
+        self.min_order
+    }
+
+    pub fn get_bulk_amount(&self) -> Option<i64> {
+        self.bulk_amount
+    }
+
+    pub fn get_bulk_discount(&self) -> f64 {
+        self.bulk_discount
+    }
+
+    pub fn get_lead_time(&self) -> i64 {
+        self.lead_time
+    }
+
+    pub fn get_reliability(&self) -> f64 {
+        self.reliability
+    }
+
+    pub fn sells(&self, ingredient: &str) -> bool {
+        self.prices.iter().any(|(name, _)| name == ingredient)
+    }
+
+    //Price of one unit of the ingredient at the given quality, before any discount
+    pub fn get_price(&self, ingredient: &str, quality: i64) -> Option<Money> {
+        self.prices
+            .iter()
+            .find(|(name, _)| name == ingredient)
+            .map(|(_, prices)| prices[(quality.clamp(1, 3) - 1) as usize])
+    }
+
+    //Checks if an order of the given amount gets the bulk discount
+    pub fn gets_discount(&self, amount: i64) -> bool {
+        self.bulk_amount.is_some_and(|bulk_amount| amount >= bulk_amount)
+    }
+
+    //Price of one unit when ordering the given amount, with the bulk discount if it applies
+    //A discounted price is rounded to the nearest cent, so it is only used to value the stock
+    pub fn get_unit_cost(&self, ingredient: &str, quality: i64, amount: i64) -> Option<Money> {
+        let price = self.get_price(ingredient, quality)?;
+        if self.gets_discount(amount) {
+            Some(price.mul_f64(1.0 - self.bulk_discount))
+        } else {
+            Some(price)
+        }
+    }
+
+    //Cost of ordering the given amount, with the bulk discount if it applies
+    //The discount comes off the whole order, rounded to the nearest cent once
+    //None if the supplier doesn't sell the ingredient or the cost is too large to hold
+    pub fn calc_order_cost(&self, ingredient: &str, quality: i64, amount: i64) -> Option<Money> {
+        let cost = self.get_price(ingredient, quality)?.checked_mul(amount)?;
+        if self.gets_discount(amount) {
+            cost.checked_mul_f64(1.0 - self.bulk_discount)
+        } else {
+            Some(cost)
+        }
+    }
+
+    //Decides what happens to an order on the day it is due
+    //Late orders come one or two days later, partial orders bring half to 90% of the units
+    pub fn deliver<R: Rng>(&self, mut order: PendingOrder, rng: &mut R) -> Delivery {
+        if rng.gen_range(0.0, 1.0) < self.reliability {
+            return Delivery::Arrived(order);
+        }
+        if rng.gen_range(0, 2) == 0 {
+            order.arrives_on += rng.gen_range(1, 3);
+            Delivery::Late(order)
+        } else {
+            let delivered = (order.quantity * rng.gen_range(50, 91) / 100).max(1);
+            let refund = order.unit_cost * (order.quantity - delivered);
+            Delivery::Partial { order, delivered, refund }
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::simulator::GameRng;
+
+#[cfg(test)]
+fn test_supplier(reliability: f64) -> Supplier {
+    Supplier::new(
+        "Test".to_owned(),
+        vec![("Bun".to_owned(), [Money::from_dollars(1), Money::from_dollars(2), Money::from_dollars(3)])],
+        10,
+        Some(100),
+        0.25,
+        2,
+        reliability,
+    )
+}
+
+#[test]
+fn price_test() {
+    let supplier = test_supplier(1.0);
+    assert_eq!(Some(Money::from_dollars(2)), supplier.get_price("Bun", 2));
+    assert_eq!(None, supplier.get_price("Patty", 2));
+    assert_eq!(Some(Money::from_dollars(2)), supplier.get_unit_cost("Bun", 2, 99));
+    assert_eq!(Some(Money::from_cents(150)), supplier.get_unit_cost("Bun", 2, 100));
+    assert_eq!(Some(Money::from_dollars(198)), supplier.calc_order_cost("Bun", 2, 99));
+    assert_eq!(Some(Money::from_dollars(150)), supplier.calc_order_cost("Bun", 2, 100));
+    assert_eq!(None, supplier.calc_order_cost("Bun", 2, i64::MAX));
+}
+
+#[test]
+fn deliver_test() {
+    let order = PendingOrder {
+        supplier: "Test".to_owned(),
+        ingredient: "Bun".to_owned(),
+        quantity: 50,
+        quality: 1,
+        unit_cost: Money::from_dollars(1),
+        arrives_on: 3,
+    };
+    match test_supplier(1.0).deliver(order.clone(), &mut GameRng::new(1)) {
+        Delivery::Arrived(arrived) => assert_eq!(50, arrived.quantity),
+        _ => panic!("a reliable supplier always delivers"),
+    }
+    for seed in 0..20 {
+        match test_supplier(0.0).deliver(order.clone(), &mut GameRng::new(seed)) {
+            Delivery::Arrived(_) => panic!("an unreliable supplier never delivers on time"),
+            Delivery::Late(late) => assert!(late.arrives_on == 4 || late.arrives_on == 5),
+            Delivery::Partial { delivered, refund, .. } => {
+                assert!((25..=45).contains(&delivered));
+                assert_eq!(Money::from_dollars(50 - delivered), refund);
+            }
+        }
+    }
+}
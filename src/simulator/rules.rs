@@ -0,0 +1,175 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following decides when a game is over.
+// The rules are picked when the game is created and checked at the end of every day.
+// The restaurant closes if it can't make payroll, stays in debt for too long
+// or misses too many payments to the bank in a row,
+// and the player wins by reaching a target balance or staying open long enough.
+// The balance is the cash on hand less what is owed to the bank,
+// so borrowing doesn't get the player any closer to the target.
+// Each condition can be turned off by setting it to None or false.
+
+use super::Money;
+use serde::{Deserialize, Serialize};
+
+// Points given for every day the restaurant stayed open
+const SCORE_PER_DAY: i64 = 10;
+// Points given for winning instead of closing
+const WIN_BONUS: i64 = 5000;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GameRules {
+    //Days in a row the balance can stay below zero before the restaurant closes
+    pub max_days_in_debt: Option<i64>,
+    //Close the restaurant if there isn't enough money to pay the employees
+    pub lose_on_unpaid_payroll: bool,
+    //Loan or credit line payments that can be missed in a row before the bank closes the restaurant
+    pub max_missed_payments: Option<i64>,
+    //Balance that wins the game
+    pub target_balance: Option<Money>,
+    //Number of days that wins the game
+    pub survive_days: Option<i64>,
+}
+
+impl Default for GameRules {
+    fn default() -> GameRules {
+        GameRules {
+            max_days_in_debt: Some(3),
+            lose_on_unpaid_payroll: true,
+            max_missed_payments: Some(3),
+            target_balance: Some(Money::from_dollars(10000)),
+            survive_days: Some(365),
+        }
+    }
+}
+
+// How a game ended
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum GameOver {
+    //Balance stayed below zero for too many days
+    Bankrupt,
+    //Not enough money to pay the employees
+    UnpaidPayroll,
+    //Missed too many payments to the bank
+    Defaulted,
+    //Balance reached the target
+    ReachedTarget,
+    //Restaurant stayed open for enough days
+    Survived,
+}
+
+impl GameOver {
+    pub fn is_win(self) -> bool {
+        match self {
+            GameOver::ReachedTarget | GameOver::Survived => true,
+            GameOver::Bankrupt | GameOver::UnpaidPayroll | GameOver::Defaulted => false,
+        }
+    }
+
+    pub fn get_description(self) -> String {
+        match self {
+            GameOver::Bankrupt => "The restaurant stayed in debt too long and went bankrupt.".to_owned(),
+            GameOver::UnpaidPayroll => "The restaurant couldn't make payroll and had to close.".to_owned(),
+            GameOver::Defaulted => "The restaurant defaulted on its debts and the bank closed it.".to_owned(),
+            GameOver::ReachedTarget => "The restaurant reached its target balance.".to_owned(),
+            GameOver::Survived => "The restaurant stayed open for the whole run.".to_owned(),
+        }
+    }
+}
+
+impl GameRules {
+    //Checks the rules at the end of a day
+    //Losing is checked before winning
+    pub fn check(
+        &self,
+        day: i64,
+        balance: Money,
+        days_in_debt: i64,
+        payroll_paid: bool,
+        missed_payments: i64,
+    ) -> Option<GameOver> {
+        if self.lose_on_unpaid_payroll && !payroll_paid {
+            return Some(GameOver::UnpaidPayroll);
+        }
+        if let Some(max_missed) = self.max_missed_payments {
+            if missed_payments >= max_missed {
+                return Some(GameOver::Defaulted);
+            }
+        }
+        if let Some(max_days) = self.max_days_in_debt {
+            if days_in_debt >= max_days {
+                return Some(GameOver::Bankrupt);
+            }
+        }
+        if let Some(target) = self.target_balance {
+            if balance >= target {
+                return Some(GameOver::ReachedTarget);
+            }
+        }
+        if let Some(days) = self.survive_days {
+            if day >= days {
+                return Some(GameOver::Survived);
+            }
+        }
+        None
+    }
+}
+
+//Final score for a game
+//The whole dollars left plus points for every day open, with a bonus for winning
+pub fn calc_score(day: i64, balance: Money, outcome: Option<GameOver>) -> i64 {
+    let mut score = balance.max(Money::ZERO).get_cents() / 100 + day * SCORE_PER_DAY;
+    if outcome.is_some_and(GameOver::is_win) {
+        score += WIN_BONUS;
+    }
+    score
+}
+
+#[test]
+fn payroll_loss_test() {
+    let rules = GameRules::default();
+    assert_eq!(Some(GameOver::UnpaidPayroll), rules.check(5, Money::from_dollars(100), 0, false, 0));
+}
+
+#[test]
+fn bankrupt_test() {
+    let rules = GameRules::default();
+    assert_eq!(None, rules.check(5, Money::from_dollars(-10), 2, true, 0));
+    assert_eq!(Some(GameOver::Bankrupt), rules.check(5, Money::from_dollars(-10), 3, true, 0));
+}
+
+#[test]
+fn default_test() {
+    let rules = GameRules::default();
+    assert_eq!(None, rules.check(5, Money::from_dollars(100), 0, true, 2));
+    assert_eq!(Some(GameOver::Defaulted), rules.check(5, Money::from_dollars(100), 0, true, 3));
+}
+
+#[test]
+fn win_test() {
+    let rules = GameRules::default();
+    assert_eq!(Some(GameOver::ReachedTarget), rules.check(5, Money::from_dollars(10000), 0, true, 0));
+    assert_eq!(Some(GameOver::Survived), rules.check(365, Money::from_dollars(500), 0, true, 0));
+}
+
+#[test]
+fn rules_off_test() {
+    let rules = GameRules {
+        max_days_in_debt: None,
+        lose_on_unpaid_payroll: false,
+        max_missed_payments: None,
+        target_balance: None,
+        survive_days: None,
+    };
+    assert_eq!(None, rules.check(1000, Money::from_dollars(-5000), 900, false, 10));
+}
+
+#[test]
+fn score_test() {
+    assert_eq!(1100, calc_score(10, Money::from_dollars(1000), None));
+    assert_eq!(100, calc_score(10, Money::from_dollars(-50), Some(GameOver::Bankrupt)));
+    assert_eq!(10000 + 100 + WIN_BONUS, calc_score(10, Money::from_dollars(10000), Some(GameOver::ReachedTarget)));
+}
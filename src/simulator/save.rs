@@ -0,0 +1,602 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following is used to save a game to disk and load it back.
+// A save file is a JSON document holding the save version and the whole simulator.
+// When the saved state changes shape the version is bumped and a migration is added,
+// so save files from older versions are brought forward one version at a time on load.
+
+use super::{GameRng, Simulator};
+use serde_json::{json, Value};
+use std::fmt;
+use std::fs;
+use std::io;
+
+// Version written into every new save file
+pub const SAVE_VERSION: i64 = 19;
+
+// Migrations between save versions.
+// The migration at index i turns a version i + 1 save into a version i + 2 save.
+const MIGRATIONS: &[fn(Value) -> Value] = &[v1_add_rng, v2_add_rules, v3_add_menu_data, v4_add_ingredients, v5_add_batches, v6_add_suppliers, v7_add_batch_quality, v8_add_schedules, v9_add_slots, v10_add_patience, v11_add_reference_prices, v12_add_segments, v13_add_reputation, v14_add_ledger, v15_use_cents, v16_add_bank, v17_add_morale, v18_add_experience];
+
+//Version 1 saves were made before games had a seed, so they are given a fresh one
+fn v1_add_rng(mut doc: Value) -> Value {
+    let seed: u64 = rand::random();
+    doc["simulator"]["seed"] = json!(seed);
+    doc["simulator"]["rng"] = json!(GameRng::new(seed));
+    doc
+}
+
+//Version 2 saves were made before games could end, so they get the default rules
+fn v2_add_rules(mut doc: Value) -> Value {
+    doc["simulator"]["rules"] = json!({
+        "max_days_in_debt": 3,
+        "lose_on_unpaid_payroll": true,
+        "target_balance": 10000.0,
+        "survive_days": 365,
+    });
+    doc["simulator"]["days_in_debt"] = json!(0);
+    doc["simulator"]["outcome"] = Value::Null;
+    doc
+}
+
+//Version 3 saves were made when the menu was always Burger, Fries and Soda,
+//so the categories and supply costs are the ones those items always had.
+//Customers who liked fries or soda now list them in their orders.
+fn v3_add_menu_data(mut doc: Value) -> Value {
+    let restaurant = &mut doc["simulator"]["restaurant"];
+
+    if let Some(menu) = restaurant["menu"].as_array_mut() {
+        for item in menu {
+            //Anything else was costed like a soda
+            let (category, supply_cost) = match item["name"].as_str() {
+                Some("Burger") => ("main", [2.50, 4.00, 5.50]),
+                Some("Fries") => ("side", [1.00, 1.50, 2.00]),
+                _ => ("drink", [0.25, 0.37, 0.50]),
+            };
+            item["category"] = json!(category);
+            item["supply_cost"] = json!(supply_cost);
+        }
+    }
+
+    if let Some(customers) = restaurant["customers"].as_array_mut() {
+        for customer in customers {
+            let mut orders = vec!["Burger"];
+            if customer["likes_fries"].as_bool() == Some(true) {
+                orders.push("Fries");
+            }
+            if customer["likes_soda"].as_bool() == Some(true) {
+                orders.push("Soda");
+            }
+            customer["orders"] = json!(orders);
+        }
+    }
+    doc
+}
+
+//Version 4 saves kept a finished-goods inventory on every menu item.
+//Each item becomes an ingredient of its own, with the item's quality, supply cost and inventory,
+//and a recipe that uses one of it, so the game plays the same as before.
+fn v4_add_ingredients(mut doc: Value) -> Value {
+    let restaurant = &mut doc["simulator"]["restaurant"];
+    let mut ingredients: Vec<Value> = Vec::new();
+
+    if let Some(menu) = restaurant["menu"].as_array_mut() {
+        for item in menu {
+            ingredients.push(json!({
+                "name": item["name"],
+                "quality": item["quality"],
+                "supply_cost": item["supply_cost"],
+                "inv": item["inv"],
+            }));
+            item["recipe"] = json!([[item["name"], 1]]);
+            if let Some(item) = item.as_object_mut() {
+                item.remove("quality");
+                item.remove("supply_cost");
+                item.remove("inv");
+            }
+        }
+    }
+    restaurant["ingredients"] = json!(ingredients);
+    doc
+}
+
+//Version 5 saves kept each ingredient's stock as a single number that never spoiled.
+//The stock becomes one batch that still never spoils, bought at the current quality,
+//and the ingredient keeps forever so the game plays the same as before.
+fn v5_add_batches(mut doc: Value) -> Value {
+    if let Some(ingredients) = doc["simulator"]["restaurant"]["ingredients"].as_array_mut() {
+        for ingredient in ingredients {
+            let quality = ingredient["quality"].as_i64().unwrap_or(1).clamp(1, 3);
+            let unit_cost = ingredient["supply_cost"][(quality - 1) as usize].clone();
+            let inv = ingredient["inv"].as_i64().unwrap_or(0);
+            let batches = if inv > 0 {
+                json!([{ "quantity": inv, "unit_cost": unit_cost, "spoils_on": null }])
+            } else {
+                json!([])
+            };
+            ingredient["batches"] = batches;
+            ingredient["shelf_life"] = Value::Null;
+            if let Some(ingredient) = ingredient.as_object_mut() {
+                ingredient.remove("inv");
+            }
+        }
+    }
+    doc
+}
+
+//Version 6 saves bought every ingredient at its supply cost with instant delivery.
+//They get a single market supplier that works the same way and no pending orders.
+fn v6_add_suppliers(mut doc: Value) -> Value {
+    let restaurant = &mut doc["simulator"]["restaurant"];
+    let prices: Vec<Value> = restaurant["ingredients"]
+        .as_array()
+        .map(|ingredients| {
+            ingredients
+                .iter()
+                .map(|ingredient| json!([ingredient["name"], ingredient["supply_cost"]]))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    restaurant["suppliers"] = json!([{
+        "name": "Market",
+        "prices": prices,
+        "min_order": 1,
+        "bulk_amount": null,
+        "bulk_discount": 0.0,
+        "lead_time": 0,
+        "reliability": 1.0,
+    }]);
+    restaurant["pending_orders"] = json!([]);
+    doc
+}
+
+//Version 7 saves had a single quality per ingredient that the whole stock was labeled with.
+//Every batch gets that quality, and customers start out having received nothing.
+fn v7_add_batch_quality(mut doc: Value) -> Value {
+    let restaurant = &mut doc["simulator"]["restaurant"];
+    if let Some(ingredients) = restaurant["ingredients"].as_array_mut() {
+        for ingredient in ingredients {
+            let quality = ingredient["quality"].clone();
+            if let Some(batches) = ingredient["batches"].as_array_mut() {
+                for batch in batches {
+                    batch["quality"] = quality.clone();
+                }
+            }
+        }
+    }
+    if let Some(customers) = restaurant["customers"].as_array_mut() {
+        for customer in customers {
+            customer["received"] = json!([]);
+        }
+    }
+    doc
+}
+
+//Version 8 saves paid every employee for 8 hours every day.
+//Every employee gets lunch and dinner every day of the week, which is the same 8 hours,
+//and is allowed to work all of them.
+fn v8_add_schedules(mut doc: Value) -> Value {
+    let shifts: Vec<Value> = (0..7)
+        .flat_map(|weekday| vec![json!([weekday, "Lunch"]), json!([weekday, "Dinner"])])
+        .collect();
+    let restaurant = &mut doc["simulator"]["restaurant"];
+    for list in &["hired_empls", "pot_empls"] {
+        if let Some(empls) = restaurant[*list].as_array_mut() {
+            for empl in empls {
+                empl["max_hours"] = json!(56);
+                empl["shifts"] = json!(shifts);
+                empl["week_hours"] = json!(0);
+            }
+        }
+    }
+    doc
+}
+
+//Version 9 saves had customers that came in during a shift instead of a time slot.
+//The customers left from the last day are put in the lunch slot.
+fn v9_add_slots(mut doc: Value) -> Value {
+    if let Some(customers) = doc["simulator"]["restaurant"]["customers"].as_array_mut() {
+        for customer in customers {
+            customer["slot"] = json!("Lunch");
+        }
+    }
+    doc
+}
+
+//Version 10 saves had customers that never waited in line.
+//The customers left from the last day were seated right away and get an average patience.
+fn v10_add_patience(mut doc: Value) -> Value {
+    if let Some(customers) = doc["simulator"]["restaurant"]["customers"].as_array_mut() {
+        for customer in customers {
+            customer["patience"] = json!(30);
+            customer["wait"] = json!(0);
+        }
+    }
+    doc
+}
+
+//Version 11 saves had customers that bought anything they could afford.
+//Every menu item expects the price it had, and the customers left from the last day have an average taste.
+fn v11_add_reference_prices(mut doc: Value) -> Value {
+    let restaurant = &mut doc["simulator"]["restaurant"];
+    if let Some(menu) = restaurant["menu"].as_array_mut() {
+        for item in menu {
+            item["reference_price"] = item["price"].clone();
+        }
+    }
+    if let Some(customers) = restaurant["customers"].as_array_mut() {
+        for customer in customers {
+            customer["taste"] = json!(1.0);
+        }
+    }
+    doc
+}
+
+//Version 12 saves had every customer come in alone and act the same.
+//The restaurant keeps the customers it had as a single segment of regulars,
+//and the customers left from the last day join it.
+fn v12_add_segments(mut doc: Value) -> Value {
+    let restaurant = &mut doc["simulator"]["restaurant"];
+    restaurant["segments"] = json!([{
+        "name": "Regulars",
+        "budget": [10.0, 100.0],
+        "likes": [],
+        "quality_premium": 0.25,
+        "price_sensitivity": 5.0,
+        "party": [1, 1],
+        "slots": [["Breakfast", 0.15], ["Lunch", 0.35], ["Afternoon", 0.1], ["Dinner", 0.3], ["LateNight", 0.1]],
+        "days": [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+    }]);
+    if let Some(customers) = restaurant["customers"].as_array_mut() {
+        for customer in customers {
+            customer["segment"] = json!("Regulars");
+            customer["party"] = json!(1);
+        }
+    }
+    doc
+}
+
+//Version 13 saves had no reviews, so the restaurant starts with none.
+fn v13_add_reputation(mut doc: Value) -> Value {
+    doc["simulator"]["restaurant"]["reputation"] = json!({ "reviews": [] });
+    doc
+}
+
+//Version 14 saves kept a single balance instead of a ledger.
+//The balance and the stock on hand are carried over as the owner's capital before day 1,
+//so statements start from the day the game was loaded.
+fn v14_add_ledger(mut doc: Value) -> Value {
+    let restaurant = &mut doc["simulator"]["restaurant"];
+    let cash = restaurant["revenue"].as_f64().unwrap_or(0.0);
+    let mut stock_value = 0.0;
+    if let Some(ingredients) = restaurant["ingredients"].as_array() {
+        for batch in ingredients.iter().filter_map(|ingredient| ingredient["batches"].as_array()).flatten() {
+            stock_value += batch["quantity"].as_f64().unwrap_or(0.0) * batch["unit_cost"].as_f64().unwrap_or(0.0);
+        }
+    }
+    let mut entries = vec![json!({
+        "day": 0, "category": "Capital", "debit": "Cash", "credit": "Equity",
+        "amount": cash, "memo": "Balance carried over",
+    })];
+    if stock_value != 0.0 {
+        entries.push(json!({
+            "day": 0, "category": "Capital", "debit": "Inventory", "credit": "Equity",
+            "amount": stock_value, "memo": "Stock carried over",
+        }));
+    }
+    restaurant["ledger"] = json!({ "entries": entries });
+    if let Some(restaurant) = restaurant.as_object_mut() {
+        restaurant.remove("revenue");
+    }
+    doc
+}
+
+//Version 15 saves kept money as dollars in floats.
+//Prices, wages, customers' cash, ledger amounts, the target balance, supply costs,
+//stock costs and segment budgets are now whole cents.
+fn v15_use_cents(mut doc: Value) -> Value {
+    fn to_cents(value: &mut Value) {
+        if let Some(dollars) = value.as_f64() {
+            *value = json!((dollars * 100.0).round() as i64);
+        }
+    }
+    fn each(list: &mut Value, field: &str) {
+        if let Some(list) = list.as_array_mut() {
+            for value in list {
+                to_cents(&mut value[field]);
+            }
+        }
+    }
+    fn all_to_cents(list: &mut Value) {
+        if let Some(list) = list.as_array_mut() {
+            list.iter_mut().for_each(to_cents);
+        }
+    }
+    to_cents(&mut doc["simulator"]["rules"]["target_balance"]);
+    let restaurant = &mut doc["simulator"]["restaurant"];
+    each(&mut restaurant["menu"], "price");
+    each(&mut restaurant["menu"], "reference_price");
+    each(&mut restaurant["hired_empls"], "wage");
+    each(&mut restaurant["pot_empls"], "wage");
+    each(&mut restaurant["customers"], "cash");
+    each(&mut restaurant["ledger"]["entries"], "amount");
+    each(&mut restaurant["pending_orders"], "unit_cost");
+    if let Some(ingredients) = restaurant["ingredients"].as_array_mut() {
+        for ingredient in ingredients {
+            all_to_cents(&mut ingredient["supply_cost"]);
+            each(&mut ingredient["batches"], "unit_cost");
+        }
+    }
+    if let Some(suppliers) = restaurant["suppliers"].as_array_mut() {
+        for supplier in suppliers {
+            if let Some(prices) = supplier["prices"].as_array_mut() {
+                for price in prices {
+                    all_to_cents(&mut price[1]);
+                }
+            }
+        }
+    }
+    if let Some(segments) = restaurant["segments"].as_array_mut() {
+        for segment in segments {
+            all_to_cents(&mut segment["budget"]);
+        }
+    }
+    doc
+}
+
+//Version 16 saves were made before the restaurant could borrow, so it owes the bank nothing.
+//The bank closes a restaurant after three missed payments in a row, as new games do.
+fn v16_add_bank(mut doc: Value) -> Value {
+    doc["simulator"]["restaurant"]["bank"] = json!({
+        "loans": [],
+        "credit": 0,
+        "credit_missed": 0,
+        "next_id": 1,
+    });
+    doc["simulator"]["rules"]["max_missed_payments"] = json!(3);
+    doc
+}
+
+//Version 17 saves were made before employees had morale,
+//so everyone starts at the morale new employees start at, as if they just had a day off.
+fn v17_add_morale(mut doc: Value) -> Value {
+    let restaurant = &mut doc["simulator"]["restaurant"];
+    for list in ["hired_empls", "pot_empls"] {
+        if let Some(empls) = restaurant[list].as_array_mut() {
+            for empl in empls {
+                empl["morale"] = json!(70.0);
+                empl["days_straight"] = json!(0);
+            }
+        }
+    }
+    doc
+}
+
+//Version 18 saves were made before employees learned on the job,
+//so nobody has any experience toward their next point, is on a course or has asked for a raise.
+fn v18_add_experience(mut doc: Value) -> Value {
+    let restaurant = &mut doc["simulator"]["restaurant"];
+    for list in ["hired_empls", "pot_empls"] {
+        if let Some(empls) = restaurant[list].as_array_mut() {
+            for empl in empls {
+                empl["experience"] = json!(0);
+                empl["course"] = Value::Null;
+                empl["asked_wage"] = Value::Null;
+            }
+        }
+    }
+    doc
+}
+
+// Everything that can go wrong while saving or loading a game
+#[derive(Debug)]
+pub enum SaveError {
+    Io(io::Error),
+    Format(serde_json::Error),
+    Version(i64),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::Io(e) => write!(f, "{}", e),
+            SaveError::Format(e) => write!(f, "save file is corrupt ({})", e),
+            SaveError::Version(v) => write!(f, "unsupported save version {}", v),
+        }
+    }
+}
+
+impl From<io::Error> for SaveError {
+    fn from(e: io::Error) -> SaveError {
+        SaveError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(e: serde_json::Error) -> SaveError {
+        SaveError::Format(e)
+    }
+}
+
+//Writes the simulator to the given file
+pub fn save_game(sim: &Simulator, path: &str) -> Result<(), SaveError> {
+    let doc = json!({
+        "version": SAVE_VERSION,
+        "simulator": sim,
+    });
+    fs::write(path, serde_json::to_string_pretty(&doc)?)?;
+    Ok(())
+}
+
+//Reads a simulator back from the given file
+//Older save files are migrated to the current version first
+pub fn load_game(path: &str) -> Result<Simulator, SaveError> {
+    let text = fs::read_to_string(path)?;
+    let doc: Value = serde_json::from_str(&text)?;
+    let mut doc = migrate(doc)?;
+    Ok(serde_json::from_value(doc["simulator"].take())?)
+}
+
+//Applies every migration between the file's version and the current version
+fn migrate(mut doc: Value) -> Result<Value, SaveError> {
+    let version = doc["version"].as_i64().unwrap_or(0);
+    if !(1..=SAVE_VERSION).contains(&version) {
+        return Err(SaveError::Version(version));
+    }
+
+    for migration in &MIGRATIONS[(version - 1) as usize..] {
+        doc = migration(doc);
+    }
+    doc["version"] = json!(SAVE_VERSION);
+
+    Ok(doc)
+}
+
+#[cfg(test)]
+use super::restaurant::segment::Segment;
+#[cfg(test)]
+use super::restaurant::ledger::{Account, Ledger};
+#[cfg(test)]
+use super::restaurant::bank::Bank;
+#[cfg(test)]
+use super::{GameRules, Money};
+
+#[cfg(test)]
+fn temp_save_path(name: &str) -> String {
+    std::env::temp_dir()
+        .join(format!("restaurant_{}_{}.json", name, std::process::id()))
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[test]
+fn save_load_test() {
+    let path = temp_save_path("save_load");
+    let mut sim = Simulator::new(super::SimConfig::new("Test".to_owned()));
+    sim.day = 12;
+    sim.order_inv("Potato".to_owned(), 20).unwrap();
+    sim.take_loan(Money::from_dollars(300), 6).unwrap();
+    sim.train_empl(0, super::employee::SERVER).unwrap();
+    save_game(&sim, &path).unwrap();
+
+    let loaded = load_game(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(12, loaded.get_day());
+    assert_eq!("Test", loaded.get_name());
+    assert_eq!(sim.get_cash(), loaded.get_cash());
+    assert_eq!(220, loaded.get_inv("Potato".to_owned()));
+    assert_eq!(sim.hired_len(), loaded.hired_len());
+    assert_eq!(sim.pot_len(), loaded.pot_len());
+    assert_eq!(sim.get_seed(), loaded.get_seed());
+    assert_eq!(Money::from_dollars(300), loaded.get_bank().get_debt());
+    assert_eq!(sim.get_hired_empls()[0].get_course(), loaded.get_hired_empls()[0].get_course());
+}
+
+#[test]
+fn migrate_v1_test() {
+    let doc = json!({ "version": 1, "simulator": { "day": 3 } });
+    let doc = migrate(doc).unwrap();
+    assert_eq!(SAVE_VERSION, doc["version"].as_i64().unwrap());
+    assert!(doc["simulator"]["seed"].is_u64());
+    assert!(doc["simulator"]["rng"].is_object());
+    let rules: GameRules = serde_json::from_value(doc["simulator"]["rules"].clone()).unwrap();
+    assert_eq!(Some(Money::from_dollars(10000)), rules.target_balance);
+    assert_eq!(Some(3), rules.max_missed_payments);
+}
+
+#[test]
+fn migrate_v3_test() {
+    let doc = json!({ "version": 3, "simulator": { "restaurant": {
+        "menu": [ { "name": "Fries", "price": 2.0, "quality": 1, "inv": 100 } ],
+        "customers": [ { "cash": 20.0, "likes_fries": false, "likes_soda": true } ],
+    } } });
+    let doc = migrate(doc).unwrap();
+    let restaurant = &doc["simulator"]["restaurant"];
+    assert_eq!("side", restaurant["menu"][0]["category"]);
+    assert_eq!(200, restaurant["menu"][0]["price"]);
+    assert_eq!(json!([100, 150, 200]), restaurant["ingredients"][0]["supply_cost"]);
+    assert_eq!(json!(["Burger", "Soda"]), restaurant["customers"][0]["orders"]);
+}
+
+#[test]
+fn migrate_v4_test() {
+    let doc = json!({ "version": 4, "simulator": { "restaurant": {
+        "menu": [ { "name": "Soda", "category": "drink", "price": 1.0, "quality": 2,
+                    "supply_cost": [0.25, 0.37, 0.5], "inv": 40 } ],
+    } } });
+    let doc = migrate(doc).unwrap();
+    let restaurant = &doc["simulator"]["restaurant"];
+    assert_eq!(json!([["Soda", 1]]), restaurant["menu"][0]["recipe"]);
+    assert!(restaurant["menu"][0]["inv"].is_null());
+    assert_eq!("Soda", restaurant["ingredients"][0]["name"]);
+    assert_eq!(2, restaurant["ingredients"][0]["quality"]);
+    assert_eq!(40, restaurant["ingredients"][0]["batches"][0]["quantity"]);
+    assert_eq!(37, restaurant["ingredients"][0]["batches"][0]["unit_cost"]);
+    assert_eq!(2, restaurant["ingredients"][0]["batches"][0]["quality"]);
+    assert!(restaurant["ingredients"][0]["shelf_life"].is_null());
+    assert_eq!(100, restaurant["menu"][0]["reference_price"]);
+    assert_eq!(json!([["Soda", [25, 37, 50]]]), restaurant["suppliers"][0]["prices"]);
+}
+
+#[test]
+fn migrate_v8_test() {
+    let doc = json!({ "version": 8, "simulator": { "restaurant": {
+        "revenue": 850.0,
+        "hired_empls": [ { "name": "Sam", "id": 1, "wage": 7.25, "rating": 5, "posit": 0 } ],
+        "customers": [ { "cash": 20.0, "orders": ["Burger"], "received": [] } ],
+    } } });
+    let doc = migrate(doc).unwrap();
+    assert_eq!("Lunch", doc["simulator"]["restaurant"]["customers"][0]["slot"]);
+    assert_eq!(30, doc["simulator"]["restaurant"]["customers"][0]["patience"]);
+    assert_eq!(1.0, doc["simulator"]["restaurant"]["customers"][0]["taste"]);
+    assert_eq!("Regulars", doc["simulator"]["restaurant"]["customers"][0]["segment"]);
+    assert_eq!(1, doc["simulator"]["restaurant"]["customers"][0]["party"]);
+    let segment: Segment = serde_json::from_value(doc["simulator"]["restaurant"]["segments"][0].clone()).unwrap();
+    assert_eq!("Regulars", segment.get_name());
+    assert_eq!((Money::from_dollars(10), Money::from_dollars(100)), segment.get_budget());
+    assert_eq!(json!([]), doc["simulator"]["restaurant"]["reputation"]["reviews"]);
+    assert_eq!(2000, doc["simulator"]["restaurant"]["customers"][0]["cash"]);
+    let ledger: Ledger = serde_json::from_value(doc["simulator"]["restaurant"]["ledger"].clone()).unwrap();
+    assert_eq!(Money::from_dollars(850), ledger.get_balance(Account::Cash));
+    assert!(doc["simulator"]["restaurant"]["revenue"].is_null());
+    let empl = &doc["simulator"]["restaurant"]["hired_empls"][0];
+    assert_eq!(56, empl["max_hours"]);
+    assert_eq!(725, empl["wage"]);
+    assert_eq!(14, empl["shifts"].as_array().unwrap().len());
+    assert_eq!(json!([6, "Dinner"]), empl["shifts"][13]);
+    assert_eq!(70.0, empl["morale"]);
+    assert_eq!(0, empl["days_straight"]);
+    assert_eq!(0, empl["experience"]);
+    assert!(empl["course"].is_null() && empl["asked_wage"].is_null());
+    let bank: Bank = serde_json::from_value(doc["simulator"]["restaurant"]["bank"].clone()).unwrap();
+    assert_eq!(Money::ZERO, bank.get_debt());
+}
+
+#[test]
+fn future_version_test() {
+    let doc = json!({ "version": SAVE_VERSION + 1, "simulator": {} });
+    match migrate(doc) {
+        Err(SaveError::Version(v)) => assert_eq!(SAVE_VERSION + 1, v),
+        _ => panic!("future save versions should be rejected"),
+    }
+}
+
+#[test]
+fn migrate_v15_test() {
+    let doc = json!({ "version": 15, "simulator": { "rules": { "target_balance": 10000.0 }, "restaurant": {
+        "menu": [ { "name": "Fries", "price": 2.5, "reference_price": 2.5 } ],
+        "pending_orders": [ { "supplier": "Metro Wholesale", "ingredient": "Cup", "quantity": 200,
+                              "quality": 1, "unit_cost": 0.051, "arrives_on": 3 } ],
+        "segments": [ { "name": "Students", "budget": [5.0, 12.5] } ],
+    } } });
+    let doc = migrate(doc).unwrap();
+    assert_eq!(1_000_000, doc["simulator"]["rules"]["target_balance"]);
+    let restaurant = &doc["simulator"]["restaurant"];
+    assert_eq!(250, restaurant["menu"][0]["price"]);
+    assert_eq!(5, restaurant["pending_orders"][0]["unit_cost"]);
+    assert_eq!(json!([500, 1250]), restaurant["segments"][0]["budget"]);
+}
@@ -0,0 +1,140 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following are the reasons a player action can be turned down.
+// The simulator checks every action before it changes anything,
+// so an action that fails leaves the game exactly as it was.
+
+use super::restaurant::bank::{MAX_WEEKS, MIN_WEEKS};
+use super::restaurant::employee::MAX_RATING;
+use super::restaurant::menu_item::MAX_PRICE;
+use super::Money;
+use std::fmt;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum SimError {
+    //The order or payment costs more than the restaurant has
+    InsufficientFunds { cost: Money, balance: Money },
+    //Amounts ordered must be more than zero, and small enough that their cost can be counted
+    InvalidQuantity(i64),
+    //No menu item has the given name
+    UnknownItem(String),
+    //No ingredient has the given name
+    UnknownIngredient(String),
+    //No supplier has the given name
+    UnknownSupplier(String),
+    //The supplier doesn't sell the ingredient
+    NotSold { supplier: String, ingredient: String },
+    //The order is smaller than the supplier takes
+    BelowMinimumOrder { min: i64, amount: i64 },
+    //No employee at the given position in a list of the given length
+    IndexOutOfRange { index: usize, len: usize },
+    NegativePrice(Money),
+    //Prices can't be more than MAX_PRICE
+    OverMaxPrice(Money),
+    //Quality must be from 1 to 3
+    InvalidQuality(i64),
+    //Days of the week go from 0 for Monday to 6 for Sunday
+    InvalidWeekday(i64),
+    //The employee already works the shift
+    AlreadyScheduled,
+    //The employee doesn't work the shift
+    NotScheduled,
+    //The shift would put the employee over the most hours they work a week
+    OverMaxHours(i64),
+    //Amounts borrowed or paid back must be more than zero
+    InvalidAmount(Money),
+    //Loans are paid back over MIN_WEEKS to MAX_WEEKS weeks
+    InvalidTerm(i64),
+    //The bank won't lend more than the limit
+    OverBorrowLimit { limit: Money, amount: Money },
+    //The bank won't lend while a payment is missed
+    MissedPayment,
+    //Nothing is owed on the credit line
+    NothingOwed,
+    //Positions go from 0 for Cook to 4 for Host
+    InvalidPosition(i64),
+    //The employee is already on a training course
+    AlreadyTraining,
+    //The employee is already rated as high as they can be in the position
+    MaxRating,
+    //The employee hasn't asked for a raise
+    NoRaiseAsked,
+}
+
+impl fmt::Display for SimError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SimError::InsufficientFunds { cost, balance } => write!(
+                f,
+                "that costs {} but the restaurant only has {}",
+                cost, balance
+            ),
+            SimError::InvalidQuantity(amount) if *amount <= 0 => {
+                write!(f, "can't order {}, the amount must be more than zero", amount)
+            }
+            SimError::InvalidQuantity(amount) => write!(f, "can't order {}, that is far too many", amount),
+            SimError::UnknownItem(name) => write!(f, "there is no {} on the menu", name),
+            SimError::UnknownIngredient(name) => write!(f, "there is no ingredient named {}", name),
+            SimError::UnknownSupplier(name) => write!(f, "there is no supplier named {}", name),
+            SimError::NotSold { supplier, ingredient } => {
+                write!(f, "{} doesn't sell {}", supplier, ingredient)
+            }
+            SimError::BelowMinimumOrder { min, amount } => write!(
+                f,
+                "can't order {}, the supplier only takes orders of {} or more",
+                amount, min
+            ),
+            SimError::IndexOutOfRange { index, len } => write!(
+                f,
+                "there is no employee number {}, the list only has {}",
+                index + 1,
+                len
+            ),
+            SimError::NegativePrice(price) => {
+                write!(f, "the price can't be negative (got {})", price)
+            }
+            SimError::OverMaxPrice(price) => {
+                write!(f, "the price can't be more than {} (got {})", MAX_PRICE, price)
+            }
+            SimError::InvalidQuality(quality) => {
+                write!(f, "quality must be from 1 to 3, not {}", quality)
+            }
+            SimError::InvalidWeekday(weekday) => write!(f, "there is no day of the week {}", weekday),
+            SimError::AlreadyScheduled => write!(f, "the employee already works that shift"),
+            SimError::NotScheduled => write!(f, "the employee doesn't work that shift"),
+            SimError::OverMaxHours(max_hours) => write!(
+                f,
+                "the employee only works up to {} hours a week",
+                max_hours
+            ),
+            SimError::InvalidAmount(amount) => {
+                write!(f, "can't use {}, the amount must be more than zero", amount)
+            }
+            SimError::InvalidTerm(weeks) => write!(
+                f,
+                "can't borrow for {} weeks, loans are paid back over {} to {} weeks",
+                weeks, MIN_WEEKS, MAX_WEEKS
+            ),
+            SimError::OverBorrowLimit { limit, amount } => write!(
+                f,
+                "can't borrow {}, the bank will only lend {}",
+                amount, limit
+            ),
+            SimError::MissedPayment => {
+                write!(f, "the bank won't lend anything until the missed payments are made")
+            }
+            SimError::NothingOwed => write!(f, "nothing is owed on the credit line"),
+            SimError::InvalidPosition(posit) => write!(f, "there is no position {}", posit),
+            SimError::AlreadyTraining => write!(f, "the employee is already on a training course"),
+            SimError::MaxRating => write!(
+                f,
+                "the employee is already rated {} in that position",
+                MAX_RATING
+            ),
+            SimError::NoRaiseAsked => write!(f, "the employee hasn't asked for a raise"),
+        }
+    }
+}
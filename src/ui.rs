@@ -3,151 +3,1031 @@
 // Please see the file COPYING in the source
 // distribution of this software for license terms.
 
-
-mod simulator;
-use simulator::Simulator;
+use restaurant_the_game::simulator::restaurant::schedule::{SHIFTS, SLOTS, WEEKDAYS};
+use restaurant_the_game::simulator::restaurant::ledger::Period;
+use restaurant_the_game::simulator::restaurant::bank;
+use restaurant_the_game::simulator::restaurant::employee::{self, POSITIONS};
+use restaurant_the_game::simulator::restaurant::overhead;
+use restaurant_the_game::simulator::restaurant::supplier::Delivery;
+use restaurant_the_game::simulator::{DayReport, GameRules, Menu, Money, SaveError, SimConfig, Simulator};
+use std::fs::{self, File};
+use std::io::{self, Write};
+mod command;
+use command::Command;
 
 // Used to interact with the user.
 // Handles taking in most inputs from the user.
 // Has a home_page function to serve as the main hub for the user.
 // Handles the inputs of the user to accomplish the management of the restaurant.
 // Most of the function calls made are to the simulator to update the restaurant.
+// All printing happens here, the simulator only hands back data.
+// The UI is a state machine: run loops over screens, and every screen
+// returns the next screen to show instead of calling the home page again.
+// Every action that changes the game goes through apply as a Command,
+// so a game can also be played from a script file and recorded to one.
+
+// The screens the player can be on
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Screen {
+    Home,
+    Menu,
+    Employees,
+    Reviews,
+    Finances,
+    Bank,
+    Hire,
+    Fire,
+    Schedule,
+    Train,
+    OrderItem,
+    ChangePrice,
+    NextDay,
+    Save,
+    Load,
+    GameOver,
+    Quit,
+}
 
 pub struct UI {
     sim: Simulator,
+    //File every command is written to while playing, if recording
+    recorder: Option<File>,
 }
 
 impl UI {
-    pub fn new() -> UI {
-        let sim = Simulator::new();
-        UI { sim }
+    //Starts a new game, from the given seed if there is one
+    //The user is asked for the restaurant name if none is given
+    //The default menu is used if none is given
+    pub fn new(name : Option<String>, seed : Option<u64>, rules : GameRules, menu : Option<Menu>) -> UI {
+        let name = match name {
+            Some(name) => name,
+            None => {
+                println!("Enter name of restaurant: ");
+                read!("{}\n")
+            }
+        };
+        let mut config = SimConfig::new(name);
+        if let Some(seed) = seed {
+            config.seed = seed;
+        }
+        config.rules = rules;
+        if let Some(menu) = menu {
+            config.menu = menu;
+        }
+        let sim = Simulator::new(config);
+        UI { sim, recorder: None }
+    }
+
+    //Starts the UI from a previously saved game
+    pub fn load(path : &str) -> Result<UI, SaveError> {
+        let sim = Simulator::load(path)?;
+        Ok(UI { sim, recorder: None })
+    }
+
+    //Starts writing every command to the given file
+    //The file begins with a comment naming the seed needed to replay it
+    pub fn record_to(&mut self, path : &str) -> io::Result<()> {
+        let mut file = File::create(path)?;
+        writeln!(file, "# {} day {} seed {}", self.sim.get_name(), self.sim.get_day() + 1, self.sim.get_seed())?;
+        self.recorder = Some(file);
+        Ok(())
+    }
+
+    //Runs every command in a script file in order
+    //Stops at the first line that can't be read or carried out,
+    //or when the game ends
+    pub fn run_script(&mut self, path : &str) -> Result<(), String> {
+        let script = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        for (i, line) in script.lines().enumerate() {
+            let command = Command::parse(line).map_err(|e| format!("{} line {}: {}", path, i + 1, e))?;
+            if let Some(command) = command {
+                println!("> {}", command);
+                self.apply(command).map_err(|e| format!("{} line {}: {}", path, i + 1, e))?;
+            }
+            if self.sim.is_over() {
+                self.game_over();
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    //Carries out a command, whether it was typed in or read from a script
+    //Every command that goes through is written to the recording if there is one, see Command::to_recording
+    pub fn apply(&mut self, command : Command) -> Result<(), String> {
+        match &command {
+            Command::Hire(position) => {
+                let index = UI::to_index(*position)?;
+                self.sim.hire_empl(index).map_err(|e| e.to_string())?;
+                UI::print_hired(&self.sim);
+            }
+            Command::Fire(position) => {
+                let index = UI::to_index(*position)?;
+                self.sim.fire_empl(index).map_err(|e| e.to_string())?;
+                UI::print_hired(&self.sim);
+            }
+            Command::Schedule(position, weekday, shift) => {
+                let index = UI::to_index(*position)?;
+                self.sim.schedule_shift(index, *weekday, *shift).map_err(|e| e.to_string())?;
+                UI::print_schedule(&self.sim);
+            }
+            Command::Unschedule(position, weekday, shift) => {
+                let index = UI::to_index(*position)?;
+                self.sim.unschedule_shift(index, *weekday, *shift).map_err(|e| e.to_string())?;
+                UI::print_schedule(&self.sim);
+            }
+            Command::Train(position, posit) => {
+                let index = UI::to_index(*position)?;
+                self.sim.train_empl(index, *posit).map_err(|e| e.to_string())?;
+                println!(
+                    "{} starts the {} course tomorrow, back on the schedule in {} days",
+                    self.sim.get_hired_empls()[index].get_name(),
+                    employee::get_posit_name(*posit).to_lowercase(),
+                    employee::COURSE_DAYS
+                );
+            }
+            Command::Raise(position) => {
+                let index = UI::to_index(*position)?;
+                let wage = self.sim.grant_raise(index).map_err(|e| e.to_string())?;
+                println!("{} now makes {} an hour", self.sim.get_hired_empls()[index].get_name(), wage);
+            }
+            Command::Order(name, amount, supplier) => {
+                let pending = self.sim.get_pending_orders().len();
+                match supplier {
+                    Some(supplier) => self.sim.order_from(supplier.clone(), name.clone(), *amount),
+                    None => self.sim.order_inv(name.clone(), *amount),
+                }
+                .map_err(|e| e.to_string())?;
+                if let Some(order) = self.sim.get_pending_orders().get(pending) {
+                    println!("{} {} will arrive at the start of day {}", order.quantity, order.ingredient, order.arrives_on);
+                }
+                println!("{} is bought at quality: {} \nCurrent inventory of {}: {}", name, self.sim.get_ingredient_quality(name.clone()), name, self.sim.get_inv(name.clone()));
+            }
+            Command::Price(name, price) => {
+                self.sim.set_item_price(name.clone(), *price).map_err(|e| e.to_string())?;
+            }
+            Command::Quality(name, quality) => {
+                self.sim.set_ingredient_quality(name.clone(), *quality).map_err(|e| e.to_string())?;
+            }
+            Command::Borrow(amount, weeks) => {
+                let id = self.sim.take_loan(*amount, *weeks).map_err(|e| e.to_string())?;
+                if let Some(loan) = self.sim.get_bank().get_loans().iter().find(|loan| loan.get_id() == id) {
+                    println!(
+                        "Borrowed {} at {:.2}% a week, {} is due every week starting day {}",
+                        loan.get_amount(),
+                        loan.get_rate() * 100.0,
+                        loan.get_payment(),
+                        loan.get_next_due()
+                    );
+                }
+            }
+            Command::Draw(amount) => {
+                self.sim.draw_credit(*amount).map_err(|e| e.to_string())?;
+                println!("Drew {}, the credit line balance is {}", amount, self.sim.get_bank().get_credit());
+            }
+            Command::Repay(amount) => {
+                let paid = self.sim.repay_credit(*amount).map_err(|e| e.to_string())?;
+                println!("Paid back {}, the credit line balance is {}", paid, self.sim.get_bank().get_credit());
+            }
+            Command::NextDay(days) => {
+                if *days < 1 {
+                    return Err(format!("can't simulate {} days", days));
+                }
+                if self.sim.is_over() {
+                    return Err("the game is over".to_owned());
+                }
+                for _ in 0..*days {
+                    let report = self.sim.sim_day();
+                    UI::print_report(&report);
+                    if report.game_over.is_some() {
+                        break;
+                    }
+                }
+            }
+            Command::Save(path) => {
+                self.sim.save(path).map_err(|e| format!("could not save game: {}", e))?;
+                println!("Game saved to {}", path);
+            }
+            Command::Load(path) => {
+                self.sim = Simulator::load(path).map_err(|e| format!("could not load game: {}", e))?;
+                println!("Game loaded from {}", path);
+            }
+        }
+
+        if let Some(file) = &mut self.recorder {
+            if let Err(e) = writeln!(file, "{}", command.to_recording()) {
+                println!("Could not record command: {}", e);
+            }
+        }
+        Ok(())
     }
+
+    //Turns an employee's position in a list, starting at 1, into an index
+    fn to_index(position : i64) -> Result<usize, String> {
+        if position < 1 {
+            return Err(format!("there is no employee number {}", position));
+        }
+        Ok((position - 1) as usize)
+    }
+
+    //Carries out a command typed in by the user and shows what went wrong, if anything
+    fn apply_input(&mut self, command : Command) {
+        if let Err(e) = self.apply(command) {
+            println!("Error: {}", e);
+        }
+    }
+
+    //Turns the user's input into a number
+    //Returns -1 if the input isn't a whole number
     pub fn parser(input : String) -> i64{
+        input.trim().parse::<i64>().unwrap_or(-1)
+    }
 
-        for a_char in input.chars() {
-          if ! a_char.is_digit(10){
-              return -1
-          }
-          else{
-            return a_char.to_digit(10).unwrap() as i64
-          }
+    //Runs the game until the player quits
+    //Every screen hands back the screen to go to next
+    //Once the game is won or lost the end of game summary is shown instead
+    pub fn run(&mut self) {
+        let mut screen = Screen::Home;
+        while screen != Screen::Quit {
+            if self.sim.is_over() {
+                screen = Screen::GameOver;
+            }
+            screen = self.show(screen);
         }
-        -1
     }
-    //The main display for the user to interact with.
-    pub fn home_page(&mut self) {
-        let mut choice : i64 = -1;
-        let mut input : String = "".to_owned();
-        if self.sim.get_day()%7 == 0 {
-            self.sim.update_pot();
+
+    //Shows a single screen and returns the next one
+    pub fn show(&mut self, screen : Screen) -> Screen {
+        match screen {
+            Screen::Home => self.home_page(),
+            Screen::Menu => self.display_menu(),
+            Screen::Employees => self.display_hired(),
+            Screen::Reviews => self.display_reviews(),
+            Screen::Finances => self.display_finances(),
+            Screen::Bank => self.bank(),
+            Screen::Hire => self.hire_emp(),
+            Screen::Fire => self.fire_emp(),
+            Screen::Schedule => self.schedule_empl(),
+            Screen::Train => self.train_empl(),
+            Screen::OrderItem => self.order_item(),
+            Screen::ChangePrice => self.change_item_price(),
+            Screen::NextDay => self.sim_day(),
+            Screen::Save => self.save_game(),
+            Screen::Load => self.load_game(),
+            Screen::GameOver => self.game_over(),
+            Screen::Quit => Screen::Quit,
         }
+    }
+
+    //The main display for the user to interact with.
+    //Running out of input quits the game.
+    pub fn home_page(&mut self) -> Screen {
         println!("{}",self.sim.get_name());
         println!("Current Day {}", self.sim.get_day()+1);
-        println!("Current Revenue : ${}", self.sim.get_revenue());
-
-        while choice < 1 || choice > 7 {
-            println!("[1] Display Menu\n[2] Display Employees\n[3] Hire Employee\n[4] Fire Employee");
-            println!("[5] Order Menu Item\n[6] Change Menu Item Price\n[7] Go To Next Day\n[8] Quit Program");
-            input = read!();
-            choice = UI::parser(input);
-            
-            if choice == 8{
-                return;
+        println!("Cash : {}", self.sim.get_cash());
+        println!("Rating : {:.1} stars", self.sim.get_reputation().get_rating());
+        if self.sim.get_days_in_debt() > 0 {
+            println!("Days in debt : {}", self.sim.get_days_in_debt());
+            if let Some(max_days) = self.sim.get_rules().max_days_in_debt {
+                println!("The restaurant will close after {} days in debt", max_days);
             }
         }
-        //Call the correct function based on user's choice
-        match choice {
-            1 => UI::display_menu(self),
-            2 => UI::display_hired(self),
-            3 => UI::hire_emp(self),
-            4 => UI::fire_emp(self),
-            5 => UI::order_item(self),
-            6 => UI::change_item_price(self),
-            7 => UI::sim_day(self),
-            _ => println!("Error"),
+        if self.sim.get_bank().get_debt() > Money::ZERO {
+            println!("Owed to the bank : {}", self.sim.get_bank().get_debt());
+        }
+        if self.sim.get_bank().get_missed() > 0 {
+            println!("Missed payments in a row : {}", self.sim.get_bank().get_missed());
+            if let Some(max_missed) = self.sim.get_rules().max_missed_payments {
+                println!("The bank will close the restaurant after {} missed payments", max_missed);
+            }
+        }
+        if !self.sim.get_pending_orders().is_empty() {
+            println!("Pending orders :");
+            for order in self.sim.get_pending_orders() {
+                println!(
+                    "\t{} {} from {}, due day {}",
+                    order.quantity, order.ingredient, order.supplier, order.arrives_on
+                );
+            }
+        }
+
+        loop {
+            println!("[1] Display Menu\n[2] Display Employees\n[3] Display Reviews\n[4] Financial Statements\n[5] Bank");
+            println!("[6] Hire Employee\n[7] Fire Employee\n[8] Schedule Employees\n[9] Train Employees\n[10] Order Ingredients");
+            println!("[11] Change Menu Item Price\n[12] Go To Next Day\n[13] Save Game\n[14] Load Game\n[15] Quit Program");
+            let input : String = read!();
+            if input.is_empty() {
+                return Screen::Quit;
+            }
+
+            //Go to the screen the user chose
+            match UI::parser(input) {
+                1 => return Screen::Menu,
+                2 => return Screen::Employees,
+                3 => return Screen::Reviews,
+                4 => return Screen::Finances,
+                5 => return Screen::Bank,
+                6 => return Screen::Hire,
+                7 => return Screen::Fire,
+                8 => return Screen::Schedule,
+                9 => return Screen::Train,
+                10 => return Screen::OrderItem,
+                11 => return Screen::ChangePrice,
+                12 => return Screen::NextDay,
+                13 => return Screen::Save,
+                14 => return Screen::Load,
+                15 => return Screen::Quit,
+                _ => continue,
+            }
         }
     }
 
     //Display functions
-    pub fn display_menu(&mut self) {
-        self.sim.display_menu();
-        UI::home_page(self);
+    pub fn display_menu(&mut self) -> Screen {
+        UI::print_menu(&self.sim);
+        Screen::Home
     }
     
-    pub fn display_hired(&mut self) {
-        self.sim.display_hired();
-        UI::home_page(self);
+    pub fn display_hired(&mut self) -> Screen {
+        UI::print_hired(&self.sim);
+        Screen::Home
+    }
+
+    pub fn display_reviews(&mut self) -> Screen {
+        UI::print_reviews(&self.sim);
+        Screen::Home
+    }
+
+    //Shows the statement for the period the user picks, or the ledger entries of the last day
+    //Entering 0 goes back to the home page
+    pub fn display_finances(&mut self) -> Screen {
+        loop {
+            println!("[1] Daily Statement\n[2] Weekly Statement\n[3] Monthly Statement\n[4] Ledger Entries");
+            println!("Enter 0 to return to home page");
+            let input : String = read!();
+            if input.is_empty() {
+                return Screen::Home;
+            }
+            match UI::parser(input) {
+                0 => return Screen::Home,
+                1 => UI::print_statement(&self.sim, Period::Day),
+                2 => UI::print_statement(&self.sim, Period::Week),
+                3 => UI::print_statement(&self.sim, Period::Month),
+                4 => UI::print_entries(&self.sim),
+                _ => continue,
+            }
+        }
+    }
+
+    //Shows what is owed to the bank and lets the user borrow or pay back the credit line
+    //Entering 0 goes back to the home page
+    pub fn bank(&mut self) -> Screen {
+        loop {
+            UI::print_bank(&self.sim);
+            println!("[1] Take Out a Loan\n[2] Draw On Credit Line\n[3] Repay Credit Line");
+            println!("Enter 0 to return to home page");
+            let input : String = read!();
+            if input.is_empty() {
+                return Screen::Home;
+            }
+            match UI::parser(input) {
+                0 => return Screen::Home,
+                1 => {
+                    let amount = match UI::read_money("Enter the amount to borrow:") {
+                        Some(amount) => amount,
+                        None => continue,
+                    };
+                    println!("Enter the number of weeks to pay it back over ({} to {}):", bank::MIN_WEEKS, bank::MAX_WEEKS);
+                    let input : String = read!();
+                    self.apply_input(Command::Borrow(amount, UI::parser(input)));
+                }
+                2 => {
+                    if let Some(amount) = UI::read_money("Enter the amount to draw:") {
+                        self.apply_input(Command::Draw(amount));
+                    }
+                }
+                3 => {
+                    if let Some(amount) = UI::read_money("Enter the amount to pay back:") {
+                        self.apply_input(Command::Repay(amount));
+                    }
+                }
+                _ => continue,
+            }
+        }
+    }
+
+    //Asks the user for an amount of money
+    //Returns None and shows what went wrong if the input isn't one
+    fn read_money(prompt : &str) -> Option<Money> {
+        println!("{}", prompt);
+        let input : String = read!();
+        match Money::parse(&input) {
+            Ok(amount) => Some(amount),
+            Err(e) => {
+                println!("Error: {}", e);
+                None
+            }
+        }
+    }
+
+    //Printing helpers
+    //Loans and credit line owed, and what the bank offers today
+    fn print_bank(sim : &Simulator) {
+        let bank = sim.get_bank();
+        if bank.get_loans().is_empty() {
+            println!("No loans");
+        }
+        for loan in bank.get_loans() {
+            println!(
+                "Loan {} : {} owed of {} borrowed at {:.2}% a week, {} due day {}",
+                loan.get_id(),
+                loan.get_balance(),
+                loan.get_amount(),
+                loan.get_rate() * 100.0,
+                loan.get_payment(),
+                loan.get_next_due()
+            );
+            if loan.get_missed() > 0 {
+                println!("\t{} payments missed in a row", loan.get_missed());
+            }
+        }
+        println!(
+            "Credit line : {} owed of {} at {:.2}% a week, payments due every Sunday",
+            bank.get_credit(),
+            bank.get_credit_limit(),
+            sim.get_credit_rate() * 100.0
+        );
+        println!(
+            "The bank will lend up to {} today at {:.2}% a week",
+            sim.get_loan_limit(),
+            sim.get_loan_rate() * 100.0
+        );
+        println!();
+    }
+
+    //Profit and loss and cash flow for the period up to the last day simulated
+    fn print_statement(sim : &Simulator, period : Period) {
+        let statement = sim.get_statement(period);
+        if period == Period::Day {
+            println!("{} statement for day {}", period.get_name(), statement.first_day);
+        } else {
+            println!("{} statement for days {} to {}", period.get_name(), statement.first_day, statement.last_day);
+        }
+        println!("Profit and loss");
+        println!("\tRevenue");
+        for (account, amount) in &statement.revenue {
+            println!("\t\t{}\t{}", account.get_name(), amount);
+        }
+        println!("\tExpenses");
+        for (account, amount) in &statement.expenses {
+            println!("\t\t{}\t{}", account.get_name(), amount);
+        }
+        println!("\tNet income\t{}", statement.net_income);
+        println!("Cash flow");
+        println!("\tOpening cash\t{}", statement.opening_cash);
+        println!("\tCash in");
+        for (category, amount) in statement.cash_in.iter().filter(|(_, amount)| *amount > Money::ZERO) {
+            println!("\t\t{}\t{}", category.get_name(), amount);
+        }
+        println!("\tCash out");
+        for (category, amount) in statement.cash_out.iter().filter(|(_, amount)| *amount > Money::ZERO) {
+            println!("\t\t{}\t{}", category.get_name(), amount);
+        }
+        println!("\tClosing cash\t{}", statement.closing_cash);
+        println!();
+    }
+
+    //Every ledger entry of the last day simulated and the day being played
+    fn print_entries(sim : &Simulator) {
+        let ledger = sim.get_ledger();
+        println!("Day\tCategory\tDebit\tCredit\tAmount\tMemo");
+        for day in sim.get_day()..=sim.get_day() + 1 {
+            for entry in ledger.get_day_entries(day) {
+                println!(
+                    "{}\t{}\t{}\t{}\t{}\t{}",
+                    entry.day,
+                    entry.category.get_name(),
+                    entry.debit.get_name(),
+                    entry.credit.get_name(),
+                    entry.amount,
+                    entry.memo
+                );
+            }
+        }
+        println!();
+    }
+
+    fn print_reviews(sim : &Simulator) {
+        let reputation = sim.get_reputation();
+        println!("Every party that is served leaves a review about their wait, the food, the prices and what was sold out.");
+        println!("The better the rating, the more customers come in.");
+        println!("Rating : {:.1} stars from {} reviews", reputation.get_rating(), reputation.get_reviews().len());
+        for review in reputation.get_reviews().iter().rev() {
+            println!("Day {}\t{}\t{}\t{}", review.day, UI::stars(review.stars), review.segment, review.text);
+        }
+        println!();
+    }
+
+    //Draws a star rating out of five
+    fn stars(stars : i64) -> String {
+        format!("{}{}", "*".repeat(stars as usize), ".".repeat((5 - stars) as usize))
+    }
+
+    fn print_hired(sim : &Simulator) {
+        println!("Every customer needs seating, a table served, food cooked and cleaning up after");
+        println!("The total rating of the employees in each position decides how many customers that stage can handle");
+        println!("The stage that can handle the fewest customers decides how many you can serve");
+        println!("Only the employees working during a time slot help the customers that come in during it");
+        println!("Underpaid, overworked or swamped employees lose morale, work below their rating and may quit");
+        println!("Employees get better with every shift they work, and ask for a raise when they do");
+        println!("\tName\tID\tWage\tPostition\tRating\tHours\tMorale\tDays in a row\tExperience");
+        for (i, empl) in sim.get_hired_empls().iter().enumerate() {
+            let rating = if empl.get_effective_rating() < empl.get_rating() {
+                format!("{} ({})", empl.get_effective_rating(), empl.get_rating())
+            } else {
+                empl.get_rating().to_string()
+            };
+            println!(
+                "[{}]\t{}\t{}\t{}\t{}\t\t{}\t{}/{}\t{:.0}\t{}\t\t{}/{}",
+                i + 1,
+                empl.get_name(),
+                empl.get_id(),
+                empl.get_wage(),
+                empl.get_posit_string(),
+                rating,
+                empl.get_sched_hours(),
+                empl.get_max_hours(),
+                empl.get_morale(),
+                empl.get_days_straight(),
+                empl.get_experience(),
+                empl.get_shifts_to_improve()
+            );
+            if let Some(course) = empl.get_course() {
+                println!("\t\ton the {} course, {} days left", employee::get_posit_name(course.posit).to_lowercase(), course.days_left);
+            }
+            if let Some(wage) = empl.get_asked_wage() {
+                println!("\t\tasking for a raise to {}", wage);
+            }
+        }
+        let weekday = sim.get_weekday();
+        println!("Customers your staff can serve on {} :", WEEKDAYS[weekday as usize]);
+        for slot in SLOTS.iter() {
+            let capacity = sim.get_capacity(weekday, *slot);
+            let (bottleneck, customers) = capacity.get_bottleneck();
+            let (start, end) = slot.get_hours();
+            println!(
+                "\t{} ({}:00-{}:00) : {}, held back by the {}",
+                slot.get_name(), start, end, customers, bottleneck.get_description()
+            );
+        }
+        println!();
+    }
+
+    //Shows which shifts every hired employee works through the week
+    fn print_schedule(sim : &Simulator) {
+        print!("\tName\tPostition");
+        for weekday in WEEKDAYS.iter() {
+            print!("\t{}", &weekday[..3]);
+        }
+        println!("\tHours");
+        for (i, empl) in sim.get_hired_empls().iter().enumerate() {
+            print!("[{}]\t{}\t{}", i + 1, empl.get_name(), empl.get_posit_string());
+            for weekday in 0..WEEKDAYS.len() as i64 {
+                let shifts : String = SHIFTS
+                    .iter()
+                    .filter(|shift| empl.works(weekday, **shift))
+                    .map(|shift| shift.get_name()[..1].to_owned())
+                    .collect();
+                print!("\t{}", if shifts.is_empty() { "-".to_owned() } else { shifts });
+            }
+            println!("\t{}/{}", empl.get_sched_hours(), empl.get_max_hours());
+        }
+        println!("M = Morning 7-11, L = Lunch 11-15, D = Dinner 15-19, C = Close 19-23");
+        println!("Hours past 40 in a week are paid at time and a half");
+        println!();
+    }
+
+    fn print_pot(sim : &Simulator) {
+        println!("\tName\tWage\tPostition\tRating");
+        for (i, empl) in sim.get_pot_empls().iter().enumerate() {
+            println!(
+                "[{}]\t{}\t{}\t{}\t\t{}",
+                i + 1,
+                empl.get_name(),
+                empl.get_wage(),
+                empl.get_posit_string(),
+                empl.get_rating()
+            );
+        }
+        println!();
+    }
+
+    fn print_menu(sim : &Simulator) {
+        println!("You'll attract more customers the higher the overall quality of your menu is.");
+        println!("A menu item is only as good as the worst ingredient in it.");
+        println!("Quality is what is on hand, changing it only changes what you buy next.");
+        println!("Customers pay more for better food, but the further a price is over the usual price the fewer buy.");
+        println!("Customers pay {:.0}% sales tax on top of the price.", overhead::SALES_TAX_RATE * 100.0);
+        println!("\tItem\tCategory\tPrice\tUsual\tBuy\tQuality\tAvailable\tRecipe");
+        for (i, item) in sim.get_menu().iter().enumerate() {
+            let recipe : Vec<String> = item
+                .get_recipe()
+                .iter()
+                .map(|(ingredient, amount)| format!("{} {}", amount, ingredient))
+                .collect();
+            println!(
+                "[{}]\t{}\t{}\t{}\t{}\t{:.0}%\t{:.1}\t{}\t{}",
+                i + 1,
+                item.get_name(),
+                item.get_category(),
+                item.get_price(),
+                item.get_reference_price(),
+                sim.get_purchase_chance(item.get_name()) * 100.0,
+                sim.get_item_quality(item.get_name()),
+                sim.get_servings(item.get_name()),
+                recipe.join(", "),
+            );
+        }
+        println!();
+        println!("\tIngredient\tOn hand\tBuying\tCost\tKeeps\tInventory");
+        for (i, ingredient) in sim.get_ingredients().iter().enumerate() {
+            let keeps = match ingredient.get_shelf_life() {
+                Some(days) => format!("{} days", days),
+                None => "always".to_owned(),
+            };
+            let on_hand = match ingredient.get_stock_quality() {
+                Some(quality) => format!("{:.1}", quality),
+                None => "-".to_owned(),
+            };
+            println!(
+                "[{}]\t{}\t{}\t{}\t{}\t{}\t{}",
+                i + 1,
+                ingredient.get_name(),
+                on_hand,
+                ingredient.get_quality(),
+                ingredient.get_supply_cost(),
+                keeps,
+                ingredient.get_inv(),
+            );
+        }
+        println!();
+        println!("Different kinds of customers come in on different days and at different times.");
+        println!("\tCustomers\tBudget\tParty\tBusiest\tBusiest day\t{} share", WEEKDAYS[sim.get_weekday() as usize]);
+        let tot_weight: f64 = sim.get_segments().iter().map(|segment| segment.get_day_weight(sim.get_weekday())).sum();
+        for segment in sim.get_segments() {
+            let (min_budget, max_budget) = segment.get_budget();
+            let (min_party, max_party) = segment.get_party();
+            let busiest_day = (0..7)
+                .fold(0, |best, weekday| if segment.get_day_weight(weekday) > segment.get_day_weight(best) { weekday } else { best });
+            println!(
+                "\t{}\t{}-{}\t{}-{}\t{}\t{}\t{:.0}%",
+                segment.get_name(),
+                min_budget,
+                max_budget,
+                min_party,
+                max_party,
+                segment.get_busiest_slot().get_name(),
+                WEEKDAYS[busiest_day as usize],
+                segment.get_day_weight(sim.get_weekday()) / tot_weight.max(f64::EPSILON) * 100.0,
+            );
+        }
+        println!();
+    }
+
+    fn print_inv(sim : &Simulator) {
+        for ingredient in sim.get_ingredients() {
+            println!("Number of {} in stock: {} ", ingredient.get_name(), ingredient.get_inv());
+            for batch in ingredient.get_batches() {
+                match batch.get_spoils_on() {
+                    Some(day) => println!("\t{} at quality {}, spoil at the end of day {}", batch.get_quantity(), batch.get_quality(), day),
+                    None => println!("\t{} at quality {}", batch.get_quantity(), batch.get_quality()),
+                }
+            }
+        }
+        for item in sim.get_menu() {
+            println!("Number of {} available to sell: {} ", item.get_name(), sim.get_servings(item.get_name()));
+        }
+        println!();
+    }
+
+    fn print_report(report : &DayReport) {
+        for empl in &report.quit {
+            println!(
+                "{} the {} quit this morning, unhappy with the job (morale {:.0})",
+                empl.get_name(),
+                empl.get_posit_string().to_lowercase(),
+                empl.get_morale()
+            );
+        }
+        for delivery in &report.deliveries {
+            match delivery {
+                Delivery::Arrived(order) => {
+                    println!("{} {} arrived from {}", order.quantity, order.ingredient, order.supplier)
+                }
+                Delivery::Partial { order, delivered, refund } => println!(
+                    "Only {} of {} {} arrived from {}, {} was refunded",
+                    delivered, order.quantity, order.ingredient, order.supplier, refund
+                ),
+                Delivery::Late(order) => println!(
+                    "{} {} from {} is late, now due day {}",
+                    order.quantity, order.ingredient, order.supplier, order.arrives_on
+                ),
+            }
+        }
+        println!("{}", WEEKDAYS[report.weekday as usize]);
+        println!("Customers servered : {}", report.customers_served);
+        for slot in &report.slots {
+            if slot.served < slot.wanted {
+                println!(
+                    "{} : {} of {} customers seated, {} walked out, held back by the {}, average wait {:.0} minutes, sales {}",
+                    slot.slot.get_name(),
+                    slot.served,
+                    slot.wanted,
+                    slot.wanted - slot.served,
+                    slot.capacity.get_bottleneck().0.get_description(),
+                    slot.avg_wait,
+                    slot.sales
+                );
+            } else {
+                println!(
+                    "{} : {} customers seated, average wait {:.0} minutes, sales {}",
+                    slot.slot.get_name(), slot.served, slot.avg_wait, slot.sales
+                );
+            }
+        }
+        for segment in &report.segments {
+            if segment.wanted > 0 {
+                println!(
+                    "{} : {} of {} seated, sales {}",
+                    segment.segment, segment.served, segment.wanted, segment.sales
+                );
+            }
+        }
+        for review in report.reviews.iter().take(3) {
+            println!("{} {} : {}", UI::stars(review.stars), review.segment, review.text);
+        }
+        if report.reviews.len() > 3 {
+            println!("...and {} more reviews", report.reviews.len() - 3);
+        }
+        println!("Rating : {:.1} stars", report.rating);
+        for (reason, count) in &report.turned_away {
+            if *count > 0 {
+                println!("{} customers left without buying anything, they {}", count, reason.get_description());
+            }
+        }
+        for (name, missed) in &report.stockouts {
+            if *missed > 0 {
+                println!("{} orders of {} were missed because it was sold out", missed, name);
+            }
+        }
+        for (name, missed) in &report.priced_out {
+            if *missed > 0 {
+                println!("{} orders of {} were missed because customers wouldn't pay the price", missed, name);
+            }
+        }
+        for (stage, customers) in &report.capacity {
+            println!("{} capacity: {}", stage.get_name(), customers);
+        }
+        println!("Bottleneck: {} ({})", report.bottleneck.get_name(), report.bottleneck.get_description());
+        for (name, sold) in &report.items_sold {
+            if *sold > 0 {
+                println!("{} Sold: {} at average quality {:.1}", name, sold, report.get_quality_served(name));
+            } else {
+                println!("{} Sold: {}", name, sold);
+            }
+        }
+        for (name, used) in &report.ingredients_used {
+            println!("{} used: {}", name, used);
+        }
+        for (name, spoiled) in &report.spoiled {
+            if *spoiled > 0 {
+                println!("{} spoiled: {}", name, spoiled);
+            }
+        }
+        println!("Waste cost: {}", report.waste_cost);
+        println!("Daily sales: {}", report.sales);
+        println!("Sales tax collected: {}", report.sales_tax);
+        println!("Cost of goods sold: {}", report.cost_of_goods);
+        println!("Labor cost: {}", report.labor_cost);
+        if report.overtime_hours > 0 {
+            println!("Overtime hours: {}", report.overtime_hours);
+        }
+        for (account, amount) in &report.overhead {
+            println!("{}: {}", account.get_name(), amount);
+        }
+        for (account, amount) in &report.taxes {
+            println!("{} paid: {}", account.get_name(), amount);
+        }
+        for payment in &report.payments {
+            if payment.missed {
+                println!(
+                    "Couldn't pay {} on {}, the interest and a late fee of {} were added to what is owed",
+                    payment.get_total(),
+                    payment.debt.get_name(),
+                    bank::LATE_FEE
+                );
+            } else {
+                println!(
+                    "Paid {} on {}, {} of it interest",
+                    payment.get_total(),
+                    payment.debt.get_name(),
+                    payment.interest
+                );
+            }
+        }
+        if report.debt > Money::ZERO {
+            println!("Owed to the bank: {}", report.debt);
+        }
+        for progress in &report.progress {
+            if progress.trained {
+                println!(
+                    "{} finished the {} course and is now rated {}",
+                    progress.name,
+                    employee::get_posit_name(progress.posit).to_lowercase(),
+                    progress.rating
+                );
+            } else {
+                println!("{} has gotten better with experience and is now rated {}", progress.name, progress.rating);
+            }
+            if let Some(wage) = progress.asked_wage {
+                println!("{} is asking for a raise to {} an hour", progress.name, wage);
+            }
+        }
+        println!("Daily profit: {}", report.profit);
+        println!("Cash flow: {}", report.cash_flow);
+        println!("Game seed: {}", report.seed);
+    }
+
+    //The end of game summary
+    pub fn game_over(&mut self) -> Screen {
+        println!();
+        match self.sim.get_outcome() {
+            Some(outcome) if outcome.is_win() => println!("You win! {}", outcome.get_description()),
+            Some(outcome) => println!("Game over. {}", outcome.get_description()),
+            None => println!("Game over."),
+        }
+        println!("{} was open for {} days", self.sim.get_name(), self.sim.get_day());
+        println!("Final balance : {}", self.sim.get_cash());
+        if self.sim.get_bank().get_debt() > Money::ZERO {
+            println!("Owed to the bank : {}", self.sim.get_bank().get_debt());
+        }
+        println!("Final score : {}", self.sim.get_score());
+        Screen::Quit
     }
 
     
     //Used to add an employee from the potential employee list to hired list
-    pub fn hire_emp(&mut self) {
-        let mut input : String = "".to_owned();
+    //Entering 0 goes back to the home page without hiring anyone
+    pub fn hire_emp(&mut self) -> Screen {
         let mut emp_select : i64 = -1;
         //Display potential employees and let user choose an employee to hire
         while emp_select < 1 || emp_select > self.sim.pot_len() {
-           self.sim.display_pot();
+           UI::print_pot(&self.sim);
             println!("Enter 0 to return to home page");
             println!("Choose employee to hire : ");
-            input = read!();
+            let input : String = read!();
+            if input.is_empty() {
+                return Screen::Home;
+            }
             emp_select = UI::parser(input);
             if emp_select == 0 {
-                UI::home_page(self);
+                return Screen::Home;
             }
         }
         //higher selected employee
-        self.sim.hire_empl((emp_select - 1) as usize);
-        self.sim.display_hired();
-        UI::home_page(self);
+        self.apply_input(Command::Hire(emp_select));
+        Screen::Home
     }
     
     //Used to remove an employee from the hired list
-    pub fn fire_emp(&mut self) {
+    //Entering 0 goes back to the home page without firing anyone
+    pub fn fire_emp(&mut self) -> Screen {
         let mut emp_select : i64 = -1;
-        let mut input : String = "".to_owned();
         //Display hired employees and let user choose one to fire
         while emp_select < 1 || emp_select > self.sim.hired_len() {
-           self.sim.display_hired();
+           UI::print_hired(&self.sim);
             println!("Enter 0 to return to home page");
            println!("Choose employee to fire : ");
-            input = read!();
+            let input : String = read!();
+            if input.is_empty() {
+                return Screen::Home;
+            }
             emp_select = UI::parser(input);
             if emp_select == 0 {
-                UI::home_page( self);
+                return Screen::Home;
             }
         }
         //Remove selected employee from the hired list
-        self.sim.fire_empl((emp_select - 1) as usize);
-        self.sim.display_hired();
-
-         UI::home_page(self);
+        self.apply_input(Command::Fire(emp_select));
+        Screen::Home
     }
 
-    pub fn order_item(&mut self) {
-        let mut name: String = "".to_owned();
-        let mut input : String = "".to_owned();
-        self.sim.display_inv();
-        println!("Enter the number of the menu item to order : ");
-        println!("[1] Burger \n[2] Fries \n[3] Soda");
-        let mut item_select: i32 = read!();
-
-        while item_select < 1 || item_select > 3 {
-            println!("Enter the number of the menu item to order: ");
-            println!("[1] Burger \n[2] Fries \n[3] Soda");
-            input = read!();
-            item_select = UI::parser(input) as i32;
+    //Used to add or remove a shift from a hired employee's schedule
+    //Entering 0 goes back to the home page without changing anything
+    pub fn schedule_empl(&mut self) -> Screen {
+        let mut emp_select : i64 = -1;
+        while emp_select < 1 || emp_select > self.sim.hired_len() {
+            UI::print_schedule(&self.sim);
+            println!("Enter 0 to return to home page");
+            println!("Choose employee to schedule : ");
+            let input : String = read!();
+            if input.is_empty() {
+                return Screen::Home;
+            }
+            emp_select = UI::parser(input);
+            if emp_select == 0 {
+                return Screen::Home;
+            }
         }
+        let weekdays : Vec<String> = WEEKDAYS.iter().map(|weekday| (*weekday).to_owned()).collect();
+        let weekday = match UI::choose("Choose the day of the week: ", &weekdays) {
+            Some(weekday) => weekday as i64,
+            None => return Screen::Home,
+        };
+        let shifts : Vec<String> = SHIFTS.iter().map(|shift| shift.get_name()).collect();
+        let shift = match UI::choose("Choose the shift: ", &shifts) {
+            Some(shift) => SHIFTS[shift],
+            None => return Screen::Home,
+        };
+        let works = self.sim.get_hired_empls()[(emp_select - 1) as usize].works(weekday, shift);
+        if works {
+            self.apply_input(Command::Unschedule(emp_select, weekday, shift));
+        } else {
+            self.apply_input(Command::Schedule(emp_select, weekday, shift));
+        }
+        Screen::Home
+    }
 
-        if item_select == 1 {
-            name = "Burger".to_owned();
+    //Used to send a hired employee on a training course or give them the raise they asked for
+    //Entering 0 goes back to the home page without changing anything
+    pub fn train_empl(&mut self) -> Screen {
+        let mut emp_select : i64 = -1;
+        while emp_select < 1 || emp_select > self.sim.hired_len() {
+            UI::print_hired(&self.sim);
+            println!("A training course costs {} and takes {} days off the schedule, with the shifts missed still paid", employee::COURSE_COST, employee::COURSE_DAYS);
+            println!("It raises the rating by {}, a course for another position starts from half the rating", employee::COURSE_GAIN);
+            println!("Enter 0 to return to home page");
+            println!("Choose employee : ");
+            let input : String = read!();
+            if input.is_empty() {
+                return Screen::Home;
+            }
+            emp_select = UI::parser(input);
+            if emp_select == 0 {
+                return Screen::Home;
+            }
+        }
+        let mut options = vec!["Send on a training course".to_owned()];
+        if let Some(wage) = self.sim.get_hired_empls()[(emp_select - 1) as usize].get_asked_wage() {
+            options.push(format!("Give the raise to {} they asked for", wage));
         }
-        if item_select == 2 {
-            name = "Fries".to_owned();
+        match UI::choose("Choose what to do: ", &options) {
+            Some(0) => {}
+            Some(_) => {
+                self.apply_input(Command::Raise(emp_select));
+                return Screen::Home;
+            }
+            None => return Screen::Home,
         }
-        if item_select == 3 {
-            name = "Soda".to_owned();
+        let posits : Vec<String> = POSITIONS.iter().map(|posit| employee::get_posit_name(*posit)).collect();
+        if let Some(posit) = UI::choose("Choose the position to train for: ", &posits) {
+            self.apply_input(Command::Train(emp_select, POSITIONS[posit]));
         }
-        println!("Current {} quality: {} \nCurrent inventory of {}: {}",name.clone(), self.sim.get_item_quality(name.clone()), name.clone(), self.sim.get_inv(name.clone()));
+        Screen::Home
+    }
+
+    //Lets the user pick a name from the given list by number
+    //Returns the index of the name picked, None if the user wants to go back to the home page
+    fn choose(prompt : &str, names : &[String]) -> Option<usize> {
+        let mut item_select: i64 = -1;
+
+        while item_select < 1 || item_select > names.len() as i64 {
+            println!("{}", prompt);
+            for (i, name) in names.iter().enumerate() {
+                println!("[{}] {}", i + 1, name);
+            }
+            println!("Enter 0 to return to home page");
+            let input : String = read!();
+            if input.is_empty() {
+                return None;
+            }
+            item_select = UI::parser(input);
+            if item_select == 0 {
+                return None;
+            }
+        }
+
+        Some((item_select - 1) as usize)
+    }
+
+    pub fn order_item(&mut self) -> Screen {
+        UI::print_inv(&self.sim);
+        let names : Vec<String> = self.sim.get_ingredients().iter().map(|ingredient| ingredient.get_name()).collect();
+        let name = match UI::choose("Enter the number of the ingredient to order: ", &names) {
+            Some(name) => names[name].clone(),
+            None => return Screen::Home,
+        };
+        println!("{} is bought at quality: {} \nCurrent inventory of {}: {}",name.clone(), self.sim.get_ingredient_quality(name.clone()), name.clone(), self.sim.get_inv(name.clone()));
 
         println!(
             "Would you like to change the quality of {}?\n y for yes, any other key for no: ",
@@ -155,79 +1035,117 @@ impl UI {
         );
 
         let change_quality: String = read!();
-        if change_quality == "y".to_owned() {
-            UI::change_item_quality(self);
+        if change_quality == "y" {
+            UI::change_ingredient_quality(self, name.clone());
         }
 
+        let supplier = match self.choose_supplier(&name) {
+            Some(supplier) => supplier,
+            None => return Screen::Home,
+        };
+
         println!("Enter amount of {} to order", name.clone());
-        input = read!();
+        let input : String = read!();
         let inc_amount = UI::parser(input);
 
-        self.sim.order_inv(name.clone(), inc_amount);
-        println!("Current {} quality: {}
-                 \nCurrent inventory of {}: {}",name.clone(), self.sim.get_item_quality(name.clone()), name.clone(), self.sim.get_inv(name.clone()));
-        UI::home_page(self);
+        self.apply_input(Command::Order(name, inc_amount, Some(supplier)));
+        Screen::Home
     }
     
-    //Used to change the quality of an item
-    pub fn change_item_quality(&mut self) {
+    //Lists the suppliers that sell the given ingredient and lets the user pick one
+    //Returns None if the user wants to go back to the home page
+    fn choose_supplier(&self, ingredient : &str) -> Option<String> {
+        let quality = self.sim.get_ingredient_quality(ingredient.to_owned());
+        let suppliers : Vec<_> = self.sim.get_suppliers().iter().filter(|supplier| supplier.sells(ingredient)).collect();
+        println!("\tSupplier\tPrice\tMinimum\tBulk discount\tDays to arrive\tReliability");
+        for supplier in &suppliers {
+            let bulk = match supplier.get_bulk_amount() {
+                Some(amount) => format!("{}% off {}+", supplier.get_bulk_discount() * 100.0, amount),
+                None => "none".to_owned(),
+            };
+            println!(
+                "\t{}\t{}\t{}\t{}\t{}\t{}%",
+                supplier.get_name(),
+                supplier.get_price(ingredient, quality).unwrap_or(Money::ZERO),
+                supplier.get_min_order(),
+                bulk,
+                supplier.get_lead_time(),
+                supplier.get_reliability() * 100.0,
+            );
+        }
+        let names : Vec<String> = suppliers.iter().map(|supplier| supplier.get_name()).collect();
+        UI::choose("Enter the number of the supplier to order from: ", &names).map(|name| names[name].clone())
+    }
+
+    //Used to change the quality the given ingredient is bought at
+    pub fn change_ingredient_quality(&mut self, name : String) {
             let mut new_quality: i64 = -1;
-            let mut name : String = "".to_owned();
-            let mut input : String = "".to_owned();
-            //Get new quality (Must be 
-            while new_quality < 1 || new_quality > 3 {
+            //Get new quality (Must be 1-3)
+            while !(1..=3).contains(&new_quality) {
                 println!("Select quality ");
                 println!("[1] Low\n[2] Medium\n[3] High");
-                input = read!();
+                let input : String = read!();
+                if input.is_empty() {
+                    return;
+                }
                 new_quality = UI::parser(input);
             }
 
-            if (new_quality == 1) {
-                name = "Burger".to_owned();
-            }
-            if (new_quality == 2) {
-                name = "Fries".to_owned();
-            }
-            if (new_quality == 3) {
-                name = "Soda".to_owned();
-            }
-
-            self.sim.set_item_quality(name.clone(), new_quality);
+            self.apply_input(Command::Quality(name, new_quality));
     }
     
     //Changes the price of a menu item
-    pub fn change_item_price(&mut self) {
-            let mut item: i64 = -1;
-            let mut name: String = " ".to_owned();
-            let mut input : String = "".to_owned();
-           //Get an item
-            while item < 1 || item > 3 {
-                println!("Select Menu Item to change ");
-                input = read!();
-                item = UI::parser(input);
-            }
+    pub fn change_item_price(&mut self) -> Screen {
+            let names : Vec<String> = self.sim.get_menu().iter().map(|item| item.get_name()).collect();
+            let name = match UI::choose("Select Menu Item to change ", &names) {
+                Some(name) => names[name].clone(),
+                None => return Screen::Home,
+            };
 
-            if item == 1 {
-                name = "Burger".to_owned();
-            }
-            if item == 2 {
-                name = "Fries".to_owned();
-            }
-            if item == 3 {
-                name = "Soda".to_owned();
+            println!(
+                "{} sells for {}, customers usually pay {} and {:.0}% of them buy it at this price.",
+                name,
+                self.sim.get_price(name.clone()),
+                self.sim.get_reference_price(name.clone()),
+                self.sim.get_purchase_chance(name.clone()) * 100.0
+            );
+            for segment in self.sim.get_segments() {
+                println!(
+                    "\t{} buy it {:.0}% of the time",
+                    segment.get_name(),
+                    self.sim.get_segment_chance(name.clone(), segment) * 100.0
+                );
             }
-
             println!("Enter the new price of {}:",name);
-            let new_price = read!();
-            self.sim.set_item_price(name.clone(), new_price); 
-            UI::home_page(self);
+            let input : String = read!();
+            match Money::parse(&input) {
+                Ok(new_price) => self.apply_input(Command::Price(name, new_price)),
+                Err(e) => println!("Error: {}", e),
+            }
+            Screen::Home
     }
 
     //Simulates a single day for the restaurant.
     //Creates customers to serve
-    //Updates revenue based on the amount sold and the cost of labor
-    pub fn sim_day(&mut self) {
-        self.sim.sim_day();
-        UI::home_page(self);
+    //Updates the cash based on the amount sold and the cost of labor
+    pub fn sim_day(&mut self) -> Screen {
+        self.apply_input(Command::NextDay(1));
+        Screen::Home
+    }
+
+    //Writes the current game to a save file chosen by the user
+    pub fn save_game(&mut self) -> Screen {
+        println!("Enter the name of the file to save to: ");
+        let path : String = read!();
+        self.apply_input(Command::Save(path));
+        Screen::Home
+    }
+
+    //Replaces the current game with one from a save file chosen by the user
+    pub fn load_game(&mut self) -> Screen {
+        println!("Enter the name of the file to load: ");
+        let path : String = read!();
+        self.apply_input(Command::Load(path));
+        Screen::Home
     }
 }
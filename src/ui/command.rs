@@ -0,0 +1,348 @@
+// Copyright © 2019 David Hogan
+// [This program is licensed under the "MIT License"]
+// Please see the file COPYING in the source
+// distribution of this software for license terms.
+
+// The following is a single player action written as one line of text.
+// Commands are what script files are made of, and what gets written
+// to a recording while the game is played.
+// A command line starts with a verb followed by its arguments:
+//     hire 3
+//     fire 1
+//     schedule 1 mon morning
+//     unschedule 1 sun dinner
+//     train 2 cook
+//     raise 2
+//     order Patty 50
+//     order Patty 200 from Metro Wholesale
+//     price Fries 2.50
+//     quality Syrup 2
+//     borrow 500 12
+//     draw 200
+//     repay 150.50
+//     next-day 7
+//     save game.json
+//     load game.json
+// schedule and unschedule take the position in the hired employee list, a day of the week and a shift.
+// train takes the position in the hired employee list and the position the course is for,
+// raise gives the employee the wage they asked for.
+// order and quality name an ingredient, price names a menu item.
+// borrow takes out a loan of the amount paid back over the number of weeks,
+// draw and repay take the amount drawn on or paid back to the credit line.
+// An order without a supplier goes to the first supplier that sells the ingredient.
+// Names may contain spaces, the number after the name is always last.
+// Blank lines and lines starting with # are skipped.
+// Saves and loads are written to a recording as comments, so they aren't replayed.
+
+use restaurant_the_game::simulator::restaurant::employee;
+use restaurant_the_game::simulator::restaurant::schedule::{self, Shift, WEEKDAYS};
+use restaurant_the_game::simulator::Money;
+use std::fmt;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Command {
+    //Position in the potential employee list, starting at 1
+    Hire(i64),
+    //Position in the hired employee list, starting at 1
+    Fire(i64),
+    //Position in the hired employee list, day of the week from 0 for Monday and shift
+    Schedule(i64, i64, Shift),
+    Unschedule(i64, i64, Shift),
+    //Position in the hired employee list and the position the course is for
+    Train(i64, i64),
+    //Position in the hired employee list
+    Raise(i64),
+    //Ingredient, amount and the supplier, if one was named
+    Order(String, i64, Option<String>),
+    Price(String, Money),
+    Quality(String, i64),
+    //Amount of a loan and the weeks it is paid back over
+    Borrow(Money, i64),
+    //Amount drawn on or paid back to the credit line
+    Draw(Money),
+    Repay(Money),
+    //Number of days to simulate
+    NextDay(i64),
+    Save(String),
+    Load(String),
+}
+
+impl Command {
+    //Turns a line of a script into a command
+    //Returns None for blank lines and comments
+    pub fn parse(line: &str) -> Result<Option<Command>, String> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+
+        let mut words: Vec<&str> = line.split_whitespace().collect();
+        let verb = words.remove(0);
+        let command = match verb {
+            "hire" => Command::Hire(Command::number(verb, &words)?),
+            "fire" => Command::Fire(Command::number(verb, &words)?),
+            "schedule" => {
+                let (position, weekday, shift) = Command::shift(verb, &words)?;
+                Command::Schedule(position, weekday, shift)
+            }
+            "unschedule" => {
+                let (position, weekday, shift) = Command::shift(verb, &words)?;
+                Command::Unschedule(position, weekday, shift)
+            }
+            "train" => match words[..] {
+                [position, posit] => Command::Train(
+                    Command::number(verb, &[position])?,
+                    employee::parse_posit(posit).ok_or_else(|| {
+                        format!("'{}' is not a position, try cook, server, washer, busser or host", posit)
+                    })?,
+                ),
+                _ => return Err("usage: train <employee> <position>".to_owned()),
+            },
+            "raise" => Command::Raise(Command::number(verb, &words)?),
+            "next-day" => {
+                if words.is_empty() {
+                    Command::NextDay(1)
+                } else {
+                    Command::NextDay(Command::number(verb, &words)?)
+                }
+            }
+            "order" => match words.iter().position(|word| *word == "from") {
+                Some(pos) if pos + 1 < words.len() => {
+                    let (name, amount) = Command::item_and_value(verb, &words[..pos])?;
+                    Command::Order(name, amount, Some(words[pos + 1..].join(" ")))
+                }
+                Some(_) => return Err("usage: order <item> <amount> from <supplier>".to_owned()),
+                None => {
+                    let (name, amount) = Command::item_and_value(verb, &words)?;
+                    Command::Order(name, amount, None)
+                }
+            },
+            "price" => {
+                let (name, price) = Command::item_and_value(verb, &words)?;
+                Command::Price(name, price)
+            }
+            "quality" => {
+                let (name, quality) = Command::item_and_value(verb, &words)?;
+                Command::Quality(name, quality)
+            }
+            "borrow" => match words[..] {
+                [amount, weeks] => Command::Borrow(
+                    Command::money(verb, &[amount])?,
+                    Command::number(verb, &[weeks])?,
+                ),
+                _ => return Err("usage: borrow <amount> <weeks>".to_owned()),
+            },
+            "draw" => Command::Draw(Command::money(verb, &words)?),
+            "repay" => Command::Repay(Command::money(verb, &words)?),
+            "save" => Command::Save(Command::path(verb, &words)?),
+            "load" => Command::Load(Command::path(verb, &words)?),
+            _ => return Err(format!("unknown command '{}'", verb)),
+        };
+
+        Ok(Some(command))
+    }
+
+    //Helper for commands that take a single number
+    fn number(verb: &str, words: &[&str]) -> Result<i64, String> {
+        match words {
+            [number] => number
+                .parse()
+                .map_err(|_| format!("'{}' is not a whole number", number)),
+            _ => Err(format!("usage: {} <number>", verb)),
+        }
+    }
+
+    //Helper for commands that take a single amount of money
+    fn money(verb: &str, words: &[&str]) -> Result<Money, String> {
+        match words {
+            [amount] => Money::parse(amount).map_err(|e| e.to_string()),
+            _ => Err(format!("usage: {} <amount>", verb)),
+        }
+    }
+
+    //Helper for commands that take an employee, a day of the week and a shift
+    fn shift(verb: &str, words: &[&str]) -> Result<(i64, i64, Shift), String> {
+        match words {
+            [position, weekday, shift] => {
+                let position = Command::number(verb, &[position])?;
+                let weekday = schedule::parse_weekday(weekday)
+                    .ok_or_else(|| format!("'{}' is not a day of the week", weekday))?;
+                let shift = Shift::parse(shift)
+                    .ok_or_else(|| format!("'{}' is not a shift, try morning, lunch, dinner or close", shift))?;
+                Ok((position, weekday, shift))
+            }
+            _ => Err(format!("usage: {} <employee> <day> <shift>", verb)),
+        }
+    }
+
+    //Helper for commands that take a menu item name followed by a value
+    fn item_and_value<T: std::str::FromStr>(
+        verb: &str,
+        words: &[&str],
+    ) -> Result<(String, T), String> {
+        if words.len() < 2 {
+            return Err(format!("usage: {} <item> <value>", verb));
+        }
+        let (name, value) = words.split_at(words.len() - 1);
+        let value = value[0]
+            .parse()
+            .map_err(|_| format!("'{}' is not a valid value for {}", value[0], verb))?;
+
+        Ok((name.join(" "), value))
+    }
+
+    //The line written to a recording for the command
+    //Saving and loading are only noted in a comment, since replaying a save would overwrite
+    //a newer save and replaying a load would depend on a file outside the script
+    pub fn to_recording(&self) -> String {
+        match self {
+            Command::Save(_) | Command::Load(_) => format!("# {}", self),
+            _ => self.to_string(),
+        }
+    }
+
+    //Helper for commands that take a file name
+    fn path(verb: &str, words: &[&str]) -> Result<String, String> {
+        match words {
+            [path] => Ok((*path).to_owned()),
+            _ => Err(format!("usage: {} <file>", verb)),
+        }
+    }
+}
+
+//Writes the command back out as a script line
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Command::Hire(position) => write!(f, "hire {}", position),
+            Command::Fire(position) => write!(f, "fire {}", position),
+            Command::Schedule(position, weekday, shift) => write!(
+                f,
+                "schedule {} {} {}",
+                position,
+                &WEEKDAYS[*weekday as usize][..3],
+                shift.get_name()
+            ),
+            Command::Unschedule(position, weekday, shift) => write!(
+                f,
+                "unschedule {} {} {}",
+                position,
+                &WEEKDAYS[*weekday as usize][..3],
+                shift.get_name()
+            ),
+            Command::Train(position, posit) => write!(
+                f,
+                "train {} {}",
+                position,
+                employee::get_posit_name(*posit).to_lowercase()
+            ),
+            Command::Raise(position) => write!(f, "raise {}", position),
+            Command::Order(name, amount, None) => write!(f, "order {} {}", name, amount),
+            Command::Order(name, amount, Some(supplier)) => {
+                write!(f, "order {} {} from {}", name, amount, supplier)
+            }
+            Command::Price(name, price) => write!(f, "price {} {}", name, price.to_decimal_string()),
+            Command::Quality(name, quality) => write!(f, "quality {} {}", name, quality),
+            Command::Borrow(amount, weeks) => write!(f, "borrow {} {}", amount.to_decimal_string(), weeks),
+            Command::Draw(amount) => write!(f, "draw {}", amount.to_decimal_string()),
+            Command::Repay(amount) => write!(f, "repay {}", amount.to_decimal_string()),
+            Command::NextDay(days) => write!(f, "next-day {}", days),
+            Command::Save(path) => write!(f, "save {}", path),
+            Command::Load(path) => write!(f, "load {}", path),
+        }
+    }
+}
+
+#[test]
+fn parse_test() {
+    assert_eq!(Some(Command::Hire(3)), Command::parse("hire 3").unwrap());
+    assert_eq!(
+        Some(Command::Order("Burger".to_owned(), 50, None)),
+        Command::parse("order Burger 50").unwrap()
+    );
+    assert_eq!(
+        Some(Command::Order("Bun".to_owned(), 200, Some("Metro Wholesale".to_owned()))),
+        Command::parse("order Bun 200 from Metro Wholesale").unwrap()
+    );
+    assert_eq!(
+        Some(Command::Price("Fries".to_owned(), Money::from_cents(250))),
+        Command::parse("  price Fries 2.50 ").unwrap()
+    );
+    assert_eq!(Some(Command::NextDay(7)), Command::parse("next-day 7").unwrap());
+    assert_eq!(Some(Command::NextDay(1)), Command::parse("next-day").unwrap());
+    assert_eq!(
+        Some(Command::Schedule(2, 0, Shift::Morning)),
+        Command::parse("schedule 2 mon morning").unwrap()
+    );
+    assert_eq!(
+        Some(Command::Borrow(Money::from_dollars(500), 12)),
+        Command::parse("borrow 500 12").unwrap()
+    );
+    assert_eq!(Some(Command::Repay(Money::from_cents(15050))), Command::parse("repay 150.50").unwrap());
+    assert_eq!(Some(Command::Train(2, employee::HOST)), Command::parse("train 2 Host").unwrap());
+}
+
+#[test]
+fn parse_item_with_spaces_test() {
+    assert_eq!(
+        Some(Command::Order("Chili Dog".to_owned(), 5, None)),
+        Command::parse("order Chili Dog 5").unwrap()
+    );
+}
+
+#[test]
+fn parse_skip_test() {
+    assert_eq!(None, Command::parse("").unwrap());
+    assert_eq!(None, Command::parse("# seed 42").unwrap());
+}
+
+#[test]
+fn parse_error_test() {
+    assert!(Command::parse("dance").is_err());
+    assert!(Command::parse("hire").is_err());
+    assert!(Command::parse("hire three").is_err());
+    assert!(Command::parse("order Burger").is_err());
+    assert!(Command::parse("order Bun 5 from").is_err());
+    assert!(Command::parse("price Fries cheap").is_err());
+    assert!(Command::parse("schedule 1 someday lunch").is_err());
+    assert!(Command::parse("schedule 1 mon brunch").is_err());
+    assert!(Command::parse("borrow 500").is_err());
+    assert!(Command::parse("draw 1.005").is_err());
+    assert!(Command::parse("train 1 chef").is_err());
+    assert!(Command::parse("train cook").is_err());
+}
+
+#[test]
+fn round_trip_test() {
+    let commands = vec![
+        Command::Hire(2),
+        Command::Fire(1),
+        Command::Schedule(3, 5, Shift::Close),
+        Command::Unschedule(1, 6, Shift::Dinner),
+        Command::Train(2, employee::BUSSER),
+        Command::Raise(2),
+        Command::Order("Soda".to_owned(), 40, None),
+        Command::Order("Rice Milk".to_owned(), 60, Some("Green Valley Farm".to_owned())),
+        Command::Price("Burger".to_owned(), Money::from_cents(625)),
+        Command::Quality("Fries".to_owned(), 3),
+        Command::Borrow(Money::from_cents(50025), 8),
+        Command::Draw(Money::from_dollars(200)),
+        Command::Repay(Money::from_cents(1999)),
+        Command::NextDay(7),
+        Command::Save("game.json".to_owned()),
+        Command::Load("game.json".to_owned()),
+    ];
+    for command in commands {
+        let line = command.to_string();
+        assert_eq!(Some(command), Command::parse(&line).unwrap());
+    }
+}
+
+#[test]
+fn recording_test() {
+    assert_eq!("next-day 7", Command::NextDay(7).to_recording());
+    let save = Command::Save("game.json".to_owned()).to_recording();
+    assert_eq!("# save game.json", save);
+    assert_eq!(None, Command::parse(&save).unwrap());
+    assert_eq!(None, Command::parse(&Command::Load("game.json".to_owned()).to_recording()).unwrap());
+}